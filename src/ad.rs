@@ -0,0 +1,243 @@
+//! Creating, updating and deleting ads.
+
+use crate::campaign::{ConfiguredStatus};
+use crate::client::{to_json, FacebookClient, GraphRequest};
+use crate::errors::FacebookError;
+use crate::fields::{AdField, Fields};
+use crate::types::{Ad, CreatedObject};
+use serde::Serialize;
+
+/// The `creative` parameter of an ad: a reference to an existing creative.
+#[derive(Serialize)]
+struct CreativeRef<'a> {
+    creative_id: &'a str,
+}
+
+/// Parameters of a new ad, named after the fields of [`Ad`].
+#[derive(Debug, Clone)]
+pub struct AdCreate {
+    pub name: String,
+    pub adset_id: String,
+    pub creative_id: String,
+    pub status: ConfiguredStatus,
+    pub bid_amount: Option<u64>,
+    pub conversion_domain: Option<String>,
+}
+
+impl AdCreate {
+    /// A paused ad showing `creative_id` in `adset_id`.
+    pub fn new(name: &str, adset_id: &str, creative_id: &str) -> Self {
+        AdCreate {
+            name: name.to_string(),
+            adset_id: adset_id.to_string(),
+            creative_id: creative_id.to_string(),
+            status: ConfiguredStatus::Paused,
+            bid_amount: None,
+            conversion_domain: None,
+        }
+    }
+
+    pub fn status(mut self, status: ConfiguredStatus) -> Self {
+        self.status = status;
+        self
+    }
+
+    pub fn bid_amount(mut self, amount: u64) -> Self {
+        self.bid_amount = Some(amount);
+        self
+    }
+
+    /// Domain the ad's conversions are attributed to, e.g. `example.com`.
+    pub fn conversion_domain(mut self, domain: &str) -> Self {
+        self.conversion_domain = Some(domain.to_string());
+        self
+    }
+
+    /// Checks that the name, ad set and creative are set, that the status is ACTIVE
+    /// or PAUSED, and that a `bid_amount` is above zero.
+    pub fn validate(&self) -> Result<(), FacebookError> {
+        if self.name.trim().is_empty() {
+            return Err(FacebookError::invalid("name must not be empty"));
+        }
+        if self.adset_id.trim().is_empty() {
+            return Err(FacebookError::invalid("adset_id must not be empty"));
+        }
+        if self.creative_id.trim().is_empty() {
+            return Err(FacebookError::invalid("creative_id must not be empty"));
+        }
+        if matches!(self.status, ConfiguredStatus::Deleted | ConfiguredStatus::Archived) {
+            return Err(FacebookError::invalid("a new ad must be ACTIVE or PAUSED"));
+        }
+        if self.bid_amount == Some(0) {
+            return Err(FacebookError::invalid("bid_amount must be greater than zero"));
+        }
+        Ok(())
+    }
+
+    fn to_form(&self) -> Vec<(String, String)> {
+        let mut form = vec![
+            ("name".to_string(), self.name.clone()),
+            ("adset_id".to_string(), self.adset_id.clone()),
+            ("creative".to_string(), to_json(&CreativeRef { creative_id: &self.creative_id })),
+            ("status".to_string(), self.status.to_string()),
+        ];
+        let optional = [
+            ("bid_amount", self.bid_amount.map(|value| value.to_string())),
+            ("conversion_domain", self.conversion_domain.clone()),
+        ];
+        form.extend(
+            optional
+                .into_iter()
+                .filter_map(|(key, value)| value.map(|value| (key.to_string(), value))),
+        );
+        form
+    }
+}
+
+/// Changes to an existing ad. Only the fields that are set are sent.
+#[derive(Debug, Clone, Default)]
+pub struct AdUpdate {
+    pub name: Option<String>,
+    pub status: Option<ConfiguredStatus>,
+    pub creative_id: Option<String>,
+    pub bid_amount: Option<u64>,
+    pub conversion_domain: Option<String>,
+}
+
+impl AdUpdate {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn name(mut self, name: &str) -> Self {
+        self.name = Some(name.to_string());
+        self
+    }
+
+    /// `PAUSED` pauses, `ARCHIVED` archives and `DELETED` deletes the ad.
+    pub fn status(mut self, status: ConfiguredStatus) -> Self {
+        self.status = Some(status);
+        self
+    }
+
+    /// Swaps the creative. Graph sends the ad back to review.
+    pub fn creative_id(mut self, creative_id: &str) -> Self {
+        self.creative_id = Some(creative_id.to_string());
+        self
+    }
+
+    pub fn bid_amount(mut self, amount: u64) -> Self {
+        self.bid_amount = Some(amount);
+        self
+    }
+
+    pub fn conversion_domain(mut self, domain: &str) -> Self {
+        self.conversion_domain = Some(domain.to_string());
+        self
+    }
+
+    /// Checks that the update changes something, that a new name or creative is
+    /// not empty, and that a `bid_amount` is above zero.
+    pub fn validate(&self) -> Result<(), FacebookError> {
+        if self.changed_fields().is_empty() {
+            return Err(FacebookError::invalid("the update does not change any field"));
+        }
+        if self.name.as_deref().is_some_and(|name| name.trim().is_empty()) {
+            return Err(FacebookError::invalid("name must not be empty"));
+        }
+        if self.creative_id.as_deref().is_some_and(|id| id.trim().is_empty()) {
+            return Err(FacebookError::invalid("creative_id must not be empty"));
+        }
+        if self.bid_amount == Some(0) {
+            return Err(FacebookError::invalid("bid_amount must be greater than zero"));
+        }
+        Ok(())
+    }
+
+    /// The ad fields this update writes.
+    pub fn changed_fields(&self) -> Fields<AdField> {
+        let changes = [
+            (AdField::Name, self.name.is_some()),
+            (AdField::Status, self.status.is_some()),
+            (AdField::Creative, self.creative_id.is_some()),
+            (AdField::BidAmount, self.bid_amount.is_some()),
+            (AdField::ConversionDomain, self.conversion_domain.is_some()),
+        ];
+        changes
+            .into_iter()
+            .filter(|(_, changed)| *changed)
+            .fold(Fields::new(), |fields, (field, _)| fields.field(field))
+    }
+
+    fn to_form(&self) -> Vec<(String, String)> {
+        let values = [
+            ("name", self.name.clone()),
+            ("status", self.status.as_ref().map(|value| value.to_string())),
+            (
+                "creative",
+                self.creative_id
+                    .as_deref()
+                    .map(|creative_id| to_json(&CreativeRef { creative_id })),
+            ),
+            ("bid_amount", self.bid_amount.map(|value| value.to_string())),
+            ("conversion_domain", self.conversion_domain.clone()),
+        ];
+        values
+            .into_iter()
+            .filter_map(|(key, value)| value.map(|value| (key.to_string(), value)))
+            .collect()
+    }
+}
+
+impl FacebookClient {
+    /// Fetches one ad with the given fields.
+    pub async fn get_ad(&self, ad_id: &str, fields: &Fields<AdField>) -> Result<Ad, FacebookError> {
+        self.execute(Self::ad_request(ad_id, fields)).await
+    }
+
+    pub(crate) fn ad_request(ad_id: &str, fields: &Fields<AdField>) -> GraphRequest {
+        GraphRequest::get(ad_id).fields(fields)
+    }
+
+    /// Creates an ad in an ad account and returns its ID.
+    ///
+    /// The request is validated locally first; see [`AdCreate::validate`].
+    pub async fn create_ad(&self, ad_account_id: &str, ad: AdCreate) -> Result<String, FacebookError> {
+        let created: CreatedObject = self.execute(Self::create_ad_request(ad_account_id, &ad)?).await?;
+        Ok(created.id)
+    }
+
+    pub(crate) fn create_ad_request(ad_account_id: &str, ad: &AdCreate) -> Result<GraphRequest, FacebookError> {
+        ad.validate()?;
+        let mut request = GraphRequest::post(format!("act_{}/ads", ad_account_id));
+        for (key, value) in ad.to_form() {
+            request = request.form(&key, value);
+        }
+        Ok(request)
+    }
+
+    /// Applies an update to an ad and returns the ad as Graph reports it afterwards,
+    /// limited to `id` and the fields that were changed.
+    pub async fn update_ad(&self, ad_id: &str, update: AdUpdate) -> Result<Ad, FacebookError> {
+        self.execute_success(Self::update_ad_request(ad_id, &update)?).await?;
+        let fields = update.changed_fields().field(AdField::Id);
+        self.get_ad(ad_id, &fields).await
+    }
+
+    pub(crate) fn update_ad_request(ad_id: &str, update: &AdUpdate) -> Result<GraphRequest, FacebookError> {
+        update.validate()?;
+        let mut request = GraphRequest::post(ad_id).idempotent(true);
+        for (key, value) in update.to_form() {
+            request = request.form(&key, value);
+        }
+        Ok(request)
+    }
+
+    pub async fn delete_ad(&self, ad_id: &str) -> Result<(), FacebookError> {
+        self.execute_success(Self::delete_ad_request(ad_id)).await
+    }
+
+    pub(crate) fn delete_ad_request(ad_id: &str) -> GraphRequest {
+        GraphRequest::delete(ad_id)
+    }
+}
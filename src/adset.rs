@@ -0,0 +1,565 @@
+//! Creating and updating ad sets.
+
+use crate::campaign::{BidStrategy, ConfiguredStatus};
+use crate::client::{to_json, FacebookClient, GraphRequest};
+use crate::errors::FacebookError;
+use crate::fields::{AdSetField, Fields};
+use crate::types::{
+    AdCampaignFrequencyControlSpecs, AdPromotedObject, AdSet, CreatedObject, DayPart, PublisherPlatform, Targeting,
+};
+
+graph_enum! {
+    /// What the advertiser pays for.
+    pub enum BillingEvent {
+        AppInstalls => "APP_INSTALLS",
+        Impressions => "IMPRESSIONS",
+        LinkClicks => "LINK_CLICKS",
+        ListingInteraction => "LISTING_INTERACTION",
+        PageLikes => "PAGE_LIKES",
+        PostEngagement => "POST_ENGAGEMENT",
+        Purchase => "PURCHASE",
+        Thruplay => "THRUPLAY",
+    }
+}
+
+graph_enum! {
+    /// What delivery of the ad set is optimized for.
+    pub enum OptimizationGoal {
+        None => "NONE",
+        AdRecallLift => "AD_RECALL_LIFT",
+        AppInstalls => "APP_INSTALLS",
+        AppInstallsAndOffsiteConversions => "APP_INSTALLS_AND_OFFSITE_CONVERSIONS",
+        Conversations => "CONVERSATIONS",
+        DerivedEvents => "DERIVED_EVENTS",
+        EngagedUsers => "ENGAGED_USERS",
+        EventResponses => "EVENT_RESPONSES",
+        Impressions => "IMPRESSIONS",
+        InAppValue => "IN_APP_VALUE",
+        LandingPageViews => "LANDING_PAGE_VIEWS",
+        LeadGeneration => "LEAD_GENERATION",
+        LinkClicks => "LINK_CLICKS",
+        MessagingPurchaseConversion => "MESSAGING_PURCHASE_CONVERSION",
+        OffsiteConversions => "OFFSITE_CONVERSIONS",
+        PageLikes => "PAGE_LIKES",
+        PostEngagement => "POST_ENGAGEMENT",
+        ProfileVisit => "PROFILE_VISIT",
+        QualityCall => "QUALITY_CALL",
+        QualityLead => "QUALITY_LEAD",
+        Reach => "REACH",
+        RemindersSet => "REMINDERS_SET",
+        Subscribers => "SUBSCRIBERS",
+        Thruplay => "THRUPLAY",
+        Value => "VALUE",
+        VisitInstagramProfile => "VISIT_INSTAGRAM_PROFILE",
+    }
+}
+
+impl OptimizationGoal {
+    /// Goals that optimize for an app, pixel, page or lead form event, which Graph
+    /// reads from the ad set's `promoted_object`.
+    pub fn requires_promoted_object(&self) -> bool {
+        matches!(
+            self,
+            OptimizationGoal::AppInstalls
+                | OptimizationGoal::AppInstallsAndOffsiteConversions
+                | OptimizationGoal::InAppValue
+                | OptimizationGoal::LeadGeneration
+                | OptimizationGoal::OffsiteConversions
+                | OptimizationGoal::PageLikes
+                | OptimizationGoal::Value
+        )
+    }
+}
+
+/// Parameters of a new ad set, named after the fields of [`AdSet`].
+///
+/// Budgets and bid amounts are in the minor unit of the ad account currency.
+/// Leave both budgets unset when the campaign uses a campaign budget (CBO).
+#[derive(Debug, Clone)]
+pub struct AdSetCreate {
+    pub name: String,
+    pub campaign_id: String,
+    pub status: ConfiguredStatus,
+    pub billing_event: BillingEvent,
+    pub optimization_goal: OptimizationGoal,
+    pub targeting: Targeting,
+    pub bid_amount: Option<u64>,
+    pub bid_strategy: Option<BidStrategy>,
+    pub daily_budget: Option<u64>,
+    pub lifetime_budget: Option<u64>,
+    pub start_time: Option<String>,
+    pub end_time: Option<String>,
+    pub adset_schedule: Vec<DayPart>,
+    pub frequency_control_specs: Vec<AdCampaignFrequencyControlSpecs>,
+    pub promoted_object: Option<AdPromotedObject>,
+}
+
+impl AdSetCreate {
+    /// A paused ad set in `campaign_id`.
+    pub fn new(
+        name: &str,
+        campaign_id: &str,
+        billing_event: BillingEvent,
+        optimization_goal: OptimizationGoal,
+        targeting: Targeting,
+    ) -> Self {
+        AdSetCreate {
+            name: name.to_string(),
+            campaign_id: campaign_id.to_string(),
+            status: ConfiguredStatus::Paused,
+            billing_event,
+            optimization_goal,
+            targeting,
+            bid_amount: None,
+            bid_strategy: None,
+            daily_budget: None,
+            lifetime_budget: None,
+            start_time: None,
+            end_time: None,
+            adset_schedule: Vec::new(),
+            frequency_control_specs: Vec::new(),
+            promoted_object: None,
+        }
+    }
+
+    pub fn status(mut self, status: ConfiguredStatus) -> Self {
+        self.status = status;
+        self
+    }
+
+    pub fn bid_amount(mut self, amount: u64) -> Self {
+        self.bid_amount = Some(amount);
+        self
+    }
+
+    pub fn bid_strategy(mut self, bid_strategy: BidStrategy) -> Self {
+        self.bid_strategy = Some(bid_strategy);
+        self
+    }
+
+    pub fn daily_budget(mut self, amount: u64) -> Self {
+        self.daily_budget = Some(amount);
+        self
+    }
+
+    pub fn lifetime_budget(mut self, amount: u64) -> Self {
+        self.lifetime_budget = Some(amount);
+        self
+    }
+
+    pub fn start_time(mut self, start_time: &str) -> Self {
+        self.start_time = Some(start_time.to_string());
+        self
+    }
+
+    pub fn end_time(mut self, end_time: &str) -> Self {
+        self.end_time = Some(end_time.to_string());
+        self
+    }
+
+    /// Runs the ad set only in these blocks. Requires a lifetime budget.
+    pub fn adset_schedule(mut self, schedule: Vec<DayPart>) -> Self {
+        self.adset_schedule = schedule;
+        self
+    }
+
+    pub fn frequency_control_specs(mut self, specs: Vec<AdCampaignFrequencyControlSpecs>) -> Self {
+        self.frequency_control_specs = specs;
+        self
+    }
+
+    pub fn promoted_object(mut self, promoted_object: AdPromotedObject) -> Self {
+        self.promoted_object = Some(promoted_object);
+        self
+    }
+
+    /// Checks the name, campaign and status, the targeting, the budgets (a lifetime
+    /// budget needs an `end_time`), the bid, the schedule, the frequency caps, and
+    /// that the optimization goal has the promoted object it requires.
+    pub fn validate(&self) -> Result<(), FacebookError> {
+        if self.name.trim().is_empty() {
+            return Err(FacebookError::invalid("name must not be empty"));
+        }
+        if self.campaign_id.trim().is_empty() {
+            return Err(FacebookError::invalid("campaign_id must not be empty"));
+        }
+        if matches!(self.status, ConfiguredStatus::Deleted | ConfiguredStatus::Archived) {
+            return Err(FacebookError::invalid("a new ad set must be ACTIVE or PAUSED"));
+        }
+        validate_targeting(&self.targeting)?;
+        validate_budgets(self.daily_budget, self.lifetime_budget)?;
+        if self.lifetime_budget.is_some() && self.end_time.is_none() {
+            return Err(FacebookError::invalid("a lifetime_budget requires an end_time"));
+        }
+        validate_bid(self.bid_strategy.as_ref(), self.bid_amount)?;
+        validate_schedule(&self.adset_schedule, self.lifetime_budget.is_some())?;
+        validate_frequency_caps(&self.frequency_control_specs)?;
+        if self.optimization_goal.requires_promoted_object() && self.promoted_object.is_none() {
+            return Err(FacebookError::InvalidRequest(format!(
+                "optimization_goal {} requires a promoted_object",
+                self.optimization_goal
+            )));
+        }
+        Ok(())
+    }
+
+    fn to_form(&self) -> Vec<(String, String)> {
+        let mut form = vec![
+            ("name".to_string(), self.name.clone()),
+            ("campaign_id".to_string(), self.campaign_id.clone()),
+            ("status".to_string(), self.status.to_string()),
+            ("billing_event".to_string(), self.billing_event.to_string()),
+            ("optimization_goal".to_string(), self.optimization_goal.to_string()),
+            ("targeting".to_string(), to_json(&self.targeting)),
+        ];
+        if !self.adset_schedule.is_empty() {
+            form.push(("adset_schedule".to_string(), to_json(&self.adset_schedule)));
+            form.push(("pacing_type".to_string(), to_json(&["day_parting"])));
+        }
+        if !self.frequency_control_specs.is_empty() {
+            form.push(("frequency_control_specs".to_string(), to_json(&self.frequency_control_specs)));
+        }
+        let optional = [
+            ("bid_amount", self.bid_amount.map(|value| value.to_string())),
+            ("bid_strategy", self.bid_strategy.as_ref().map(|value| value.to_string())),
+            ("daily_budget", self.daily_budget.map(|value| value.to_string())),
+            ("lifetime_budget", self.lifetime_budget.map(|value| value.to_string())),
+            ("start_time", self.start_time.clone()),
+            ("end_time", self.end_time.clone()),
+            ("promoted_object", self.promoted_object.as_ref().map(to_json)),
+        ];
+        form.extend(
+            optional
+                .into_iter()
+                .filter_map(|(key, value)| value.map(|value| (key.to_string(), value))),
+        );
+        form
+    }
+}
+
+/// Changes to an existing ad set. Only the fields that are set are sent; a new
+/// `targeting` replaces the old one as a whole.
+#[derive(Debug, Clone, Default)]
+pub struct AdSetUpdate {
+    pub name: Option<String>,
+    pub status: Option<ConfiguredStatus>,
+    pub billing_event: Option<BillingEvent>,
+    pub optimization_goal: Option<OptimizationGoal>,
+    pub targeting: Option<Targeting>,
+    pub bid_amount: Option<u64>,
+    pub bid_strategy: Option<BidStrategy>,
+    pub daily_budget: Option<u64>,
+    pub lifetime_budget: Option<u64>,
+    pub start_time: Option<String>,
+    pub end_time: Option<String>,
+    pub adset_schedule: Option<Vec<DayPart>>,
+    pub frequency_control_specs: Option<Vec<AdCampaignFrequencyControlSpecs>>,
+    pub promoted_object: Option<AdPromotedObject>,
+}
+
+impl AdSetUpdate {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn name(mut self, name: &str) -> Self {
+        self.name = Some(name.to_string());
+        self
+    }
+
+    pub fn status(mut self, status: ConfiguredStatus) -> Self {
+        self.status = Some(status);
+        self
+    }
+
+    pub fn billing_event(mut self, billing_event: BillingEvent) -> Self {
+        self.billing_event = Some(billing_event);
+        self
+    }
+
+    pub fn optimization_goal(mut self, optimization_goal: OptimizationGoal) -> Self {
+        self.optimization_goal = Some(optimization_goal);
+        self
+    }
+
+    pub fn targeting(mut self, targeting: Targeting) -> Self {
+        self.targeting = Some(targeting);
+        self
+    }
+
+    pub fn bid_amount(mut self, amount: u64) -> Self {
+        self.bid_amount = Some(amount);
+        self
+    }
+
+    pub fn bid_strategy(mut self, bid_strategy: BidStrategy) -> Self {
+        self.bid_strategy = Some(bid_strategy);
+        self
+    }
+
+    pub fn daily_budget(mut self, amount: u64) -> Self {
+        self.daily_budget = Some(amount);
+        self
+    }
+
+    pub fn lifetime_budget(mut self, amount: u64) -> Self {
+        self.lifetime_budget = Some(amount);
+        self
+    }
+
+    pub fn start_time(mut self, start_time: &str) -> Self {
+        self.start_time = Some(start_time.to_string());
+        self
+    }
+
+    pub fn end_time(mut self, end_time: &str) -> Self {
+        self.end_time = Some(end_time.to_string());
+        self
+    }
+
+    /// Replaces the schedule. Graph only accepts one on ad sets with a lifetime budget.
+    pub fn adset_schedule(mut self, schedule: Vec<DayPart>) -> Self {
+        self.adset_schedule = Some(schedule);
+        self
+    }
+
+    pub fn frequency_control_specs(mut self, specs: Vec<AdCampaignFrequencyControlSpecs>) -> Self {
+        self.frequency_control_specs = Some(specs);
+        self
+    }
+
+    pub fn promoted_object(mut self, promoted_object: AdPromotedObject) -> Self {
+        self.promoted_object = Some(promoted_object);
+        self
+    }
+
+    /// Checks that the update changes something, and applies the ad set rules to
+    /// the fields it sets. Rules that depend on fields this update leaves alone
+    /// are left to Graph.
+    pub fn validate(&self) -> Result<(), FacebookError> {
+        if self.changed_fields().is_empty() {
+            return Err(FacebookError::invalid("the update does not change any field"));
+        }
+        if self.name.as_deref().is_some_and(|name| name.trim().is_empty()) {
+            return Err(FacebookError::invalid("name must not be empty"));
+        }
+        if let Some(targeting) = &self.targeting {
+            validate_targeting(targeting)?;
+        }
+        validate_budgets(self.daily_budget, self.lifetime_budget)?;
+        if self.bid_strategy.is_some() {
+            validate_bid(self.bid_strategy.as_ref(), self.bid_amount)?;
+        }
+        if let Some(schedule) = &self.adset_schedule {
+            // The ad set may already have a lifetime budget; only a switch to a daily one is rejected.
+            validate_schedule(schedule, self.daily_budget.is_none())?;
+        }
+        if let Some(specs) = &self.frequency_control_specs {
+            validate_frequency_caps(specs)?;
+        }
+        if let Some(goal) = self.optimization_goal {
+            if goal.requires_promoted_object() && self.promoted_object.is_none() {
+                return Err(FacebookError::InvalidRequest(format!(
+                    "optimization_goal {} requires a promoted_object",
+                    goal
+                )));
+            }
+        }
+        Ok(())
+    }
+
+    /// The ad set fields this update writes.
+    pub fn changed_fields(&self) -> Fields<AdSetField> {
+        let changes = [
+            (AdSetField::Name, self.name.is_some()),
+            (AdSetField::Status, self.status.is_some()),
+            (AdSetField::BillingEvent, self.billing_event.is_some()),
+            (AdSetField::OptimizationGoal, self.optimization_goal.is_some()),
+            (AdSetField::Targeting, self.targeting.is_some()),
+            (AdSetField::BidAmount, self.bid_amount.is_some()),
+            (AdSetField::BidStrategy, self.bid_strategy.is_some()),
+            (AdSetField::DailyBudget, self.daily_budget.is_some()),
+            (AdSetField::LifetimeBudget, self.lifetime_budget.is_some()),
+            (AdSetField::StartTime, self.start_time.is_some()),
+            (AdSetField::EndTime, self.end_time.is_some()),
+            (AdSetField::AdsetSchedule, self.adset_schedule.is_some()),
+            (AdSetField::FrequencyControlSpecs, self.frequency_control_specs.is_some()),
+            (AdSetField::PromotedObject, self.promoted_object.is_some()),
+        ];
+        changes
+            .into_iter()
+            .filter(|(_, changed)| *changed)
+            .fold(Fields::new(), |fields, (field, _)| fields.field(field))
+    }
+
+    fn to_form(&self) -> Vec<(String, String)> {
+        let values = [
+            ("name", self.name.clone()),
+            ("status", self.status.as_ref().map(|value| value.to_string())),
+            ("billing_event", self.billing_event.map(|value| value.to_string())),
+            ("optimization_goal", self.optimization_goal.map(|value| value.to_string())),
+            ("targeting", self.targeting.as_ref().map(to_json)),
+            ("bid_amount", self.bid_amount.map(|value| value.to_string())),
+            ("bid_strategy", self.bid_strategy.as_ref().map(|value| value.to_string())),
+            ("daily_budget", self.daily_budget.map(|value| value.to_string())),
+            ("lifetime_budget", self.lifetime_budget.map(|value| value.to_string())),
+            ("start_time", self.start_time.clone()),
+            ("end_time", self.end_time.clone()),
+            ("adset_schedule", self.adset_schedule.as_ref().map(to_json)),
+            ("frequency_control_specs", self.frequency_control_specs.as_ref().map(to_json)),
+            ("promoted_object", self.promoted_object.as_ref().map(to_json)),
+        ];
+        let mut form: Vec<(String, String)> = values
+            .into_iter()
+            .filter_map(|(key, value)| value.map(|value| (key.to_string(), value)))
+            .collect();
+        if self.adset_schedule.as_ref().is_some_and(|schedule| !schedule.is_empty()) {
+            form.push(("pacing_type".to_string(), to_json(&["day_parting"])));
+        }
+        form
+    }
+}
+
+fn validate_targeting(targeting: &Targeting) -> Result<(), FacebookError> {
+    if targeting.geo_locations.as_ref().is_none_or(|geo| geo.is_empty()) {
+        return Err(FacebookError::invalid("targeting needs at least one location in geo_locations"));
+    }
+    if let Some(age_min) = targeting.age_min {
+        if age_min < 13 {
+            return Err(FacebookError::invalid("targeting age_min must be at least 13"));
+        }
+    }
+    if let Some(age_max) = targeting.age_max {
+        if age_max > 65 {
+            return Err(FacebookError::invalid("targeting age_max must be at most 65"));
+        }
+    }
+    if let (Some(age_min), Some(age_max)) = (targeting.age_min, targeting.age_max) {
+        if age_min > age_max {
+            return Err(FacebookError::invalid("targeting age_min must not exceed age_max"));
+        }
+    }
+    if let Some(genders) = &targeting.genders {
+        if genders.iter().any(|gender| !matches!(gender, 1 | 2)) {
+            return Err(FacebookError::invalid("targeting genders must be 1 (male) or 2 (female)"));
+        }
+    }
+    if let Some(platforms) = &targeting.publisher_platforms {
+        let positions = [
+            (PublisherPlatform::Facebook, &targeting.facebook_positions),
+            (PublisherPlatform::Instagram, &targeting.instagram_positions),
+            (PublisherPlatform::AudienceNetwork, &targeting.audience_network_positions),
+            (PublisherPlatform::Messenger, &targeting.messenger_positions),
+        ];
+        for (platform, positions) in positions {
+            if positions.as_ref().is_some_and(|positions| !positions.is_empty()) && !platforms.contains(&platform) {
+                return Err(FacebookError::invalid(&format!(
+                    "targeting sets {} positions but publisher_platforms does not include {}",
+                    platform, platform
+                )));
+            }
+        }
+    }
+    if let Some(automation) = &targeting.targeting_automation {
+        if automation.advantage_audience.is_some_and(|value| value > 1) {
+            return Err(FacebookError::invalid("targeting_automation advantage_audience must be 0 or 1"));
+        }
+    }
+    Ok(())
+}
+
+fn validate_budgets(daily_budget: Option<u64>, lifetime_budget: Option<u64>) -> Result<(), FacebookError> {
+    if daily_budget.is_some() && lifetime_budget.is_some() {
+        return Err(FacebookError::invalid("set either daily_budget or lifetime_budget, not both"));
+    }
+    if daily_budget == Some(0) || lifetime_budget == Some(0) {
+        return Err(FacebookError::invalid("budgets must be greater than zero"));
+    }
+    Ok(())
+}
+
+fn validate_bid(bid_strategy: Option<&BidStrategy>, bid_amount: Option<u64>) -> Result<(), FacebookError> {
+    let needs_bid_amount = matches!(
+        bid_strategy,
+        Some(BidStrategy::LowestCostWithBidCap) | Some(BidStrategy::CostCap)
+    );
+    if needs_bid_amount && bid_amount.is_none() {
+        return Err(FacebookError::invalid("LOWEST_COST_WITH_BID_CAP and COST_CAP require a bid_amount"));
+    }
+    if bid_amount == Some(0) {
+        return Err(FacebookError::invalid("bid_amount must be greater than zero"));
+    }
+    Ok(())
+}
+
+fn validate_schedule(schedule: &[DayPart], has_lifetime_budget: bool) -> Result<(), FacebookError> {
+    if schedule.is_empty() {
+        return Ok(());
+    }
+    if !has_lifetime_budget {
+        return Err(FacebookError::invalid("adset_schedule requires a lifetime_budget"));
+    }
+    for part in schedule {
+        let end_minute = part.end_minute.unwrap_or(24 * 60);
+        if part.start_minute < 0 || end_minute > 24 * 60 || part.start_minute >= end_minute {
+            return Err(FacebookError::invalid(
+                "adset_schedule blocks must satisfy 0 <= start_minute < end_minute <= 1440",
+            ));
+        }
+        if part.start_minute % 60 != 0 || end_minute % 60 != 0 {
+            return Err(FacebookError::invalid("adset_schedule blocks must start and end on the hour"));
+        }
+        if part.days.as_ref().is_some_and(|days| days.iter().any(|day| !(0..=6).contains(day))) {
+            return Err(FacebookError::invalid("adset_schedule days run from 0 (Sunday) to 6 (Saturday)"));
+        }
+    }
+    Ok(())
+}
+
+fn validate_frequency_caps(specs: &[AdCampaignFrequencyControlSpecs]) -> Result<(), FacebookError> {
+    for spec in specs {
+        if spec.event.is_none() || spec.interval_days.is_none() || spec.max_frequency.is_none() {
+            return Err(FacebookError::invalid("frequency_control_specs need event, interval_days and max_frequency"));
+        }
+        if spec.interval_days.is_some_and(|days| !(1..=90).contains(&days)) {
+            return Err(FacebookError::invalid("frequency_control_specs interval_days must be between 1 and 90"));
+        }
+    }
+    Ok(())
+}
+
+impl FacebookClient {
+    /// Creates an ad set in an ad account and returns its ID.
+    ///
+    /// The request is validated locally first; see [`AdSetCreate::validate`].
+    pub async fn create_ad_set(&self, ad_account_id: &str, ad_set: AdSetCreate) -> Result<String, FacebookError> {
+        let created: CreatedObject = self.execute(Self::create_ad_set_request(ad_account_id, &ad_set)?).await?;
+        Ok(created.id)
+    }
+
+    pub(crate) fn create_ad_set_request(ad_account_id: &str, ad_set: &AdSetCreate) -> Result<GraphRequest, FacebookError> {
+        ad_set.validate()?;
+        let mut request = GraphRequest::post(format!("act_{}/adsets", ad_account_id));
+        for (key, value) in ad_set.to_form() {
+            request = request.form(&key, value);
+        }
+        Ok(request)
+    }
+
+    /// Applies an update to an ad set and returns the ad set as Graph reports it
+    /// afterwards, limited to `id` and the fields that were changed.
+    pub async fn update_ad_set(&self, ad_set_id: &str, update: AdSetUpdate) -> Result<AdSet, FacebookError> {
+        self.execute_success(Self::update_ad_set_request(ad_set_id, &update)?).await?;
+        let fields = update.changed_fields().field(AdSetField::Id);
+        self.get_ad_set(ad_set_id, &fields).await
+    }
+
+    pub(crate) fn update_ad_set_request(ad_set_id: &str, update: &AdSetUpdate) -> Result<GraphRequest, FacebookError> {
+        update.validate()?;
+        let mut request = GraphRequest::post(ad_set_id).idempotent(true);
+        for (key, value) in update.to_form() {
+            request = request.form(&key, value);
+        }
+        Ok(request)
+    }
+}
@@ -0,0 +1,999 @@
+//! Custom audiences built from customer lists, and lookalikes of them.
+//!
+//! Customer data is normalized and SHA-256 hashed locally; only hashes (and mobile
+//! advertiser IDs, which Graph takes as is) leave the process.
+//! See <https://developers.facebook.com/docs/marketing-api/audiences/guides/custom-audiences>.
+
+use crate::client::{to_json, FacebookClient, GraphRequest};
+use crate::errors::FacebookError;
+use crate::fields::{CustomAudienceField, Fields};
+use crate::pagination::{ListParams, Paginator};
+use crate::types::{CreatedObject, CustomAudience, CustomAudienceStatus, CustomAudiencesResponse, GeoLocations};
+use reqwest::Method;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+/// Graph's limit on the number of users in one call to the `users` edge.
+pub const MAX_USERS_PER_BATCH: usize = 10_000;
+
+/// Graph's bounds on a lookalike's `ratio`, the share of the countries' population it covers.
+const LOOKALIKE_RATIO: std::ops::RangeInclusive<f64> = 0.01..=0.20;
+
+/// `delivery_status` code of an audience that ads can be delivered to.
+const DELIVERY_READY: i64 = 200;
+
+/// Lowest `delivery_status` code of an audience that cannot be used for delivery.
+const DELIVERY_FAILED: i64 = 400;
+
+/// Lowest `operation_status` code of an error. Codes from 400 below it are warnings,
+/// such as a low match rate, that leave the audience usable.
+const OPERATION_FAILED: i64 = 500;
+
+graph_enum! {
+    /// Kind of custom audience.
+    pub enum CustomAudienceSubtype {
+        Custom => "CUSTOM",
+        Website => "WEBSITE",
+        App => "APP",
+        OfflineConversion => "OFFLINE_CONVERSION",
+        Claim => "CLAIM",
+        Partner => "PARTNER",
+        Managed => "MANAGED",
+        Video => "VIDEO",
+        Lookalike => "LOOKALIKE",
+        Engagement => "ENGAGEMENT",
+        BagOfAccounts => "BAG_OF_ACCOUNTS",
+        StudyRuleAudience => "STUDY_RULE_AUDIENCE",
+        Fox => "FOX",
+    }
+}
+
+graph_enum! {
+    /// Who collected the customer data of a customer list audience.
+    pub enum CustomerFileSource {
+        UserProvidedOnly => "USER_PROVIDED_ONLY",
+        PartnerProvidedOnly => "PARTNER_PROVIDED_ONLY",
+        BothUserAndPartnerProvided => "BOTH_USER_AND_PARTNER_PROVIDED",
+    }
+}
+
+graph_enum! {
+    /// A column of a customer list.
+    pub enum MatchKey {
+        Email => "EMAIL",
+        Phone => "PHONE",
+        FirstName => "FN",
+        LastName => "LN",
+        Zip => "ZIP",
+        Country => "COUNTRY",
+        MobileAdvertiserId => "MADID",
+    }
+}
+
+impl MatchKey {
+    /// Brings a raw value into the form Graph matches on: trimmed, lowercased, and
+    /// phones reduced to their digits without a leading `+` or `00`. Empty values stay empty.
+    ///
+    /// A phone must already start with its country code: a national number cannot be
+    /// told apart from an international one, so only those starting with a trunk `0`
+    /// are rejected.
+    pub fn normalize(&self, raw: &str) -> Result<String, FacebookError> {
+        self.normalize_value(raw).map_err(FacebookError::invalid)
+    }
+
+    /// Whether Graph expects the value SHA-256 hashed. Mobile advertiser IDs are sent as is.
+    pub fn is_hashed(&self) -> bool {
+        !matches!(self, MatchKey::MobileAdvertiserId)
+    }
+
+    /// Normalizes a raw value and hashes it when Graph expects a hash.
+    pub fn prepare(&self, raw: &str) -> Result<String, FacebookError> {
+        self.prepare_value(raw).map_err(FacebookError::invalid)
+    }
+
+    fn prepare_value(&self, raw: &str) -> Result<String, &'static str> {
+        let value = self.normalize_value(raw)?;
+        if value.is_empty() || !self.is_hashed() {
+            return Ok(value);
+        }
+        Ok(hex::encode(Sha256::digest(value.as_bytes())))
+    }
+
+    // Errors never quote the value, so customer data does not end up in logs.
+    fn normalize_value(&self, raw: &str) -> Result<String, &'static str> {
+        let value = raw.trim().to_lowercase();
+        if value.is_empty() {
+            return Ok(value);
+        }
+        match self {
+            MatchKey::Email => {
+                let valid = value
+                    .split_once('@')
+                    .is_some_and(|(local, domain)| !local.is_empty() && domain.contains('.'));
+                if !valid {
+                    return Err("EMAIL is not an email address");
+                }
+                Ok(value)
+            }
+            MatchKey::Phone => normalize_phone(&value),
+            MatchKey::FirstName | MatchKey::LastName => Ok(value.chars().filter(|c| c.is_alphabetic()).collect()),
+            MatchKey::Zip => Ok(normalize_zip(&value)),
+            MatchKey::Country => {
+                if value.len() != 2 || !value.chars().all(|c| c.is_ascii_lowercase()) {
+                    return Err("COUNTRY must be a two-letter ISO 3166-1 code");
+                }
+                Ok(value)
+            }
+            MatchKey::MobileAdvertiserId => Ok(value),
+        }
+    }
+}
+
+fn normalize_phone(value: &str) -> Result<String, &'static str> {
+    let digits: String = value.chars().filter(char::is_ascii_digit).collect();
+    // `00` is the international call prefix; no country code starts with `0`, so any
+    // other leading zero is a national trunk prefix.
+    let digits = digits.strip_prefix("00").unwrap_or(&digits);
+    if digits.starts_with('0') {
+        return Err("PHONE must start with its country code, not a national trunk prefix 0");
+    }
+    if !(7..=15).contains(&digits.len()) {
+        return Err("PHONE must have between 7 and 15 digits");
+    }
+    Ok(digits.to_string())
+}
+
+fn normalize_zip(value: &str) -> String {
+    // US ZIP+4 codes are matched on their first five digits.
+    let value = match value.split_once('-') {
+        Some((zip, plus4))
+            if zip.len() == 5
+                && plus4.len() == 4
+                && zip.chars().chain(plus4.chars()).all(|c| c.is_ascii_digit()) =>
+        {
+            zip
+        }
+        _ => value,
+    };
+    value.chars().filter(|c| !c.is_whitespace() && *c != '-').collect()
+}
+
+/// Parameters of a new customer list audience.
+#[derive(Debug, Clone)]
+pub struct CustomAudienceCreate {
+    pub name: String,
+    pub subtype: CustomAudienceSubtype,
+    pub customer_file_source: Option<CustomerFileSource>,
+    pub description: Option<String>,
+}
+
+impl CustomAudienceCreate {
+    /// An empty customer list audience; add users with [`FacebookClient::add_audience_users`].
+    pub fn new(name: &str, customer_file_source: CustomerFileSource) -> Self {
+        CustomAudienceCreate {
+            name: name.to_string(),
+            subtype: CustomAudienceSubtype::Custom,
+            customer_file_source: Some(customer_file_source),
+            description: None,
+        }
+    }
+
+    pub fn description(mut self, description: &str) -> Self {
+        self.description = Some(description.to_string());
+        self
+    }
+
+    /// Checks that the name is set and that a CUSTOM audience names its `customer_file_source`.
+    pub fn validate(&self) -> Result<(), FacebookError> {
+        if self.name.trim().is_empty() {
+            return Err(FacebookError::invalid("name must not be empty"));
+        }
+        if self.subtype == CustomAudienceSubtype::Custom && self.customer_file_source.is_none() {
+            return Err(FacebookError::invalid("a CUSTOM audience needs a customer_file_source"));
+        }
+        Ok(())
+    }
+
+    fn to_form(&self) -> Vec<(String, String)> {
+        let mut form = vec![
+            ("name".to_string(), self.name.clone()),
+            ("subtype".to_string(), self.subtype.to_string()),
+        ];
+        let optional = [
+            ("customer_file_source", self.customer_file_source.map(|value| value.to_string())),
+            ("description", self.description.clone()),
+        ];
+        form.extend(
+            optional
+                .into_iter()
+                .filter_map(|(key, value)| value.map(|value| (key.to_string(), value))),
+        );
+        form
+    }
+}
+
+/// Changes to an existing custom audience. Only the fields that are set are sent.
+#[derive(Debug, Clone, Default)]
+pub struct CustomAudienceUpdate {
+    pub name: Option<String>,
+    pub description: Option<String>,
+    pub customer_file_source: Option<CustomerFileSource>,
+}
+
+impl CustomAudienceUpdate {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn name(mut self, name: &str) -> Self {
+        self.name = Some(name.to_string());
+        self
+    }
+
+    pub fn description(mut self, description: &str) -> Self {
+        self.description = Some(description.to_string());
+        self
+    }
+
+    pub fn customer_file_source(mut self, source: CustomerFileSource) -> Self {
+        self.customer_file_source = Some(source);
+        self
+    }
+
+    /// Checks that the update changes something and that a new name is not empty.
+    pub fn validate(&self) -> Result<(), FacebookError> {
+        if self.changed_fields().is_empty() {
+            return Err(FacebookError::invalid("the update does not change any field"));
+        }
+        if self.name.as_deref().is_some_and(|name| name.trim().is_empty()) {
+            return Err(FacebookError::invalid("name must not be empty"));
+        }
+        Ok(())
+    }
+
+    /// The custom audience fields this update writes.
+    pub fn changed_fields(&self) -> Fields<CustomAudienceField> {
+        let changes = [
+            (CustomAudienceField::Name, self.name.is_some()),
+            (CustomAudienceField::Description, self.description.is_some()),
+            (CustomAudienceField::CustomerFileSource, self.customer_file_source.is_some()),
+        ];
+        changes
+            .into_iter()
+            .filter(|(_, changed)| *changed)
+            .fold(Fields::new(), |fields, (field, _)| fields.field(field))
+    }
+
+    fn to_form(&self) -> Vec<(String, String)> {
+        let values = [
+            ("name", self.name.clone()),
+            ("description", self.description.clone()),
+            ("customer_file_source", self.customer_file_source.map(|value| value.to_string())),
+        ];
+        values
+            .into_iter()
+            .filter_map(|(key, value)| value.map(|value| (key.to_string(), value)))
+            .collect()
+    }
+}
+
+graph_enum! {
+    /// How a lookalike audience is built when no `ratio` is set.
+    pub enum LookalikeType {
+        /// The people most similar to the origin audience (about 1% of the countries).
+        Similarity => "similarity",
+        /// A larger, less similar audience (about 5% of the countries).
+        Reach => "reach",
+        CustomRatio => "custom_ratio",
+    }
+}
+
+/// Parameters of a new lookalike audience.
+#[derive(Debug, Clone)]
+pub struct LookalikeAudienceCreate {
+    pub name: String,
+    pub origin_audience_id: String,
+    /// Two-letter country codes the lookalike is drawn from.
+    pub countries: Vec<String>,
+    pub ratio: Option<f64>,
+    /// Excludes the most similar share, e.g. `0.01` with a `ratio` of `0.02` builds the 1-2% band.
+    pub starting_ratio: Option<f64>,
+    pub lookalike_type: Option<LookalikeType>,
+    pub description: Option<String>,
+}
+
+#[derive(Serialize)]
+struct LookalikeSpec<'a> {
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+    lookalike_type: Option<LookalikeType>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    ratio: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    starting_ratio: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    country: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    location_spec: Option<LookalikeLocationSpec>,
+}
+
+#[derive(Serialize)]
+struct LookalikeLocationSpec {
+    geo_locations: GeoLocations,
+}
+
+impl LookalikeAudienceCreate {
+    /// A lookalike of `origin_audience_id` in `countries`. Set a `ratio` or a type before creating it.
+    pub fn new<S: AsRef<str>>(name: &str, origin_audience_id: &str, countries: &[S]) -> Self {
+        LookalikeAudienceCreate {
+            name: name.to_string(),
+            origin_audience_id: origin_audience_id.to_string(),
+            countries: countries.iter().map(|country| country.as_ref().to_uppercase()).collect(),
+            ratio: None,
+            starting_ratio: None,
+            lookalike_type: None,
+            description: None,
+        }
+    }
+
+    /// Share of the countries' population to include, from `0.01` to `0.20`.
+    pub fn ratio(mut self, ratio: f64) -> Self {
+        self.ratio = Some(ratio);
+        self
+    }
+
+    pub fn starting_ratio(mut self, starting_ratio: f64) -> Self {
+        self.starting_ratio = Some(starting_ratio);
+        self
+    }
+
+    pub fn lookalike_type(mut self, lookalike_type: LookalikeType) -> Self {
+        self.lookalike_type = Some(lookalike_type);
+        self
+    }
+
+    pub fn description(mut self, description: &str) -> Self {
+        self.description = Some(description.to_string());
+        self
+    }
+
+    /// Checks the name, origin and countries (two-letter ISO codes), that a ratio
+    /// or type is given, that the ratio lies between 0.01 and 0.20, and that a
+    /// `starting_ratio` comes with a ratio and lies below it.
+    pub fn validate(&self) -> Result<(), FacebookError> {
+        if self.name.trim().is_empty() {
+            return Err(FacebookError::invalid("name must not be empty"));
+        }
+        if self.origin_audience_id.trim().is_empty() {
+            return Err(FacebookError::invalid("origin_audience_id must not be empty"));
+        }
+        if self.countries.is_empty() {
+            return Err(FacebookError::invalid("a lookalike audience needs at least one country"));
+        }
+        if self
+            .countries
+            .iter()
+            .any(|country| country.len() != 2 || !country.chars().all(|c| c.is_ascii_uppercase()))
+        {
+            return Err(FacebookError::invalid("countries must be two-letter ISO 3166-1 codes"));
+        }
+        match (self.ratio, self.lookalike_type) {
+            (None, None) => return Err(FacebookError::invalid("a lookalike audience needs a ratio or a type")),
+            (None, Some(LookalikeType::CustomRatio)) => {
+                return Err(FacebookError::invalid("a custom_ratio lookalike needs a ratio"))
+            }
+            (Some(ratio), _) if !LOOKALIKE_RATIO.contains(&ratio) => {
+                return Err(FacebookError::invalid("ratio must be between 0.01 and 0.20"))
+            }
+            _ => {}
+        }
+        if let Some(starting_ratio) = self.starting_ratio {
+            match self.ratio {
+                None => return Err(FacebookError::invalid("starting_ratio needs a ratio")),
+                Some(ratio) if !(0.0..ratio).contains(&starting_ratio) => {
+                    return Err(FacebookError::invalid("starting_ratio must be at least 0 and below ratio"))
+                }
+                Some(_) => {}
+            }
+        }
+        Ok(())
+    }
+
+    fn to_form(&self) -> Vec<(String, String)> {
+        // One country goes in `country`; several need a `location_spec`.
+        let (country, location_spec) = match self.countries.as_slice() {
+            [country] => (Some(country.as_str()), None),
+            countries => (
+                None,
+                Some(LookalikeLocationSpec {
+                    geo_locations: GeoLocations {
+                        countries: Some(countries.to_vec()),
+                        ..Default::default()
+                    },
+                }),
+            ),
+        };
+        let spec = LookalikeSpec {
+            lookalike_type: self.lookalike_type,
+            ratio: self.ratio,
+            starting_ratio: self.starting_ratio,
+            country,
+            location_spec,
+        };
+        let mut form = vec![
+            ("name".to_string(), self.name.clone()),
+            ("subtype".to_string(), CustomAudienceSubtype::Lookalike.to_string()),
+            ("origin_audience_id".to_string(), self.origin_audience_id.clone()),
+            ("lookalike_spec".to_string(), to_json(&spec)),
+        ];
+        if let Some(description) = &self.description {
+            form.push(("description".to_string(), description.clone()));
+        }
+        form
+    }
+}
+
+/// Graph's estimate of the number of people in an audience.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ApproximateCount {
+    pub lower_bound: i64,
+    pub upper_bound: i64,
+}
+
+impl CustomAudience {
+    /// Whether ads can be delivered to the audience, per its `delivery_status`.
+    pub fn is_ready(&self) -> bool {
+        self.delivery_status
+            .as_ref()
+            .is_some_and(|status| status.code == DELIVERY_READY)
+    }
+
+    /// Whether Graph reports an error that waiting will not clear: a `delivery_status`
+    /// from 400 or an `operation_status` from 500.
+    pub fn has_failed(&self) -> bool {
+        self.delivery_status
+            .as_ref()
+            .is_some_and(|status| status.code >= DELIVERY_FAILED)
+            || self
+                .operation_status
+                .as_ref()
+                .is_some_and(|status| status.code >= OPERATION_FAILED)
+    }
+}
+
+/// Customer list rows for the `users` edge, with raw values in `schema` order.
+///
+/// Values are normalized and hashed when the users are sent; see [`MatchKey::prepare`].
+/// Leave a value empty when it is unknown for a row.
+#[derive(Debug, Clone)]
+pub struct AudienceUsers {
+    pub schema: Vec<MatchKey>,
+    pub rows: Vec<Vec<String>>,
+    /// Upload session; a new one is generated from the clock when unset.
+    pub session_id: Option<u64>,
+}
+
+impl AudienceUsers {
+    pub fn new(schema: &[MatchKey]) -> Self {
+        AudienceUsers {
+            schema: schema.to_vec(),
+            rows: Vec::new(),
+            session_id: None,
+        }
+    }
+
+    pub fn row<S: AsRef<str>>(mut self, values: &[S]) -> Self {
+        self.push(values);
+        self
+    }
+
+    pub fn push<S: AsRef<str>>(&mut self, values: &[S]) {
+        self.rows.push(values.iter().map(|value| value.as_ref().to_string()).collect());
+    }
+
+    /// Uploads in an existing session, e.g. to resend the batches of an interrupted
+    /// replace without starting it over.
+    pub fn session_id(mut self, session_id: u64) -> Self {
+        self.session_id = Some(session_id);
+        self
+    }
+
+    pub fn len(&self) -> usize {
+        self.rows.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.rows.is_empty()
+    }
+
+    /// Checks that the schema lists each match key once, that there are rows,
+    /// and that every row has one value per key.
+    pub fn validate(&self) -> Result<(), FacebookError> {
+        if self.schema.is_empty() {
+            return Err(FacebookError::invalid("the schema needs at least one match key"));
+        }
+        if self.schema.iter().enumerate().any(|(i, key)| self.schema[..i].contains(key)) {
+            return Err(FacebookError::invalid("the schema lists a match key twice"));
+        }
+        if self.rows.is_empty() {
+            return Err(FacebookError::invalid("there are no users to send"));
+        }
+        if let Some(i) = self.rows.iter().position(|row| row.len() != self.schema.len()) {
+            return Err(FacebookError::invalid(&format!(
+                "row {} has {} values but the schema has {} keys",
+                i + 1,
+                self.rows[i].len(),
+                self.schema.len()
+            )));
+        }
+        Ok(())
+    }
+
+    /// The rows as sent to Graph: normalized, and hashed where Graph expects a hash.
+    ///
+    /// A value that cannot be normalized is sent empty and listed in
+    /// [`PreparedUsers::rejected`]; a row left with no value is not sent at all.
+    /// Fails only when the list does not pass [`Self::validate`] or no row has a value.
+    pub fn prepared_rows(&self) -> Result<PreparedUsers, FacebookError> {
+        self.validate()?;
+        let mut prepared = PreparedUsers {
+            rows: Vec::with_capacity(self.rows.len()),
+            rejected: Vec::new(),
+            skipped_rows: Vec::new(),
+        };
+        for (i, row) in self.rows.iter().enumerate() {
+            let mut values = Vec::with_capacity(row.len());
+            for (key, value) in self.schema.iter().zip(row) {
+                match key.prepare_value(value) {
+                    Ok(value) => values.push(value),
+                    Err(reason) => {
+                        prepared.rejected.push(RejectedValue { row: i, key: *key, reason });
+                        values.push(String::new());
+                    }
+                }
+            }
+            if values.iter().all(String::is_empty) {
+                prepared.skipped_rows.push(i);
+            } else {
+                prepared.rows.push(values);
+            }
+        }
+        if prepared.rows.is_empty() {
+            return Err(FacebookError::invalid("none of the rows has a valid value"));
+        }
+        Ok(prepared)
+    }
+}
+
+/// Customer list rows ready to send, with what was left out of them.
+#[derive(Debug, Clone)]
+pub struct PreparedUsers {
+    pub rows: Vec<Vec<String>>,
+    /// Values that could not be normalized and were sent empty instead.
+    pub rejected: Vec<RejectedValue>,
+    /// Indices in [`AudienceUsers::rows`] of the rows left with no value, which are not sent.
+    pub skipped_rows: Vec<usize>,
+}
+
+/// A value of a customer list that could not be normalized. The value itself is not
+/// kept, so customer data does not end up in logs.
+#[derive(Debug, Clone)]
+pub struct RejectedValue {
+    /// Index in [`AudienceUsers::rows`].
+    pub row: usize,
+    pub key: MatchKey,
+    pub reason: &'static str,
+}
+
+#[derive(Serialize)]
+struct UsersPayload<'a> {
+    schema: &'a [MatchKey],
+    data: &'a [Vec<String>],
+}
+
+#[derive(Serialize)]
+struct UsersSession {
+    session_id: u64,
+    batch_seq: usize,
+    last_batch_flag: bool,
+    estimated_num_total: usize,
+}
+
+/// Graph's reply to one batch of users.
+#[derive(Deserialize, Debug, Clone)]
+pub struct AudienceUsersResponse {
+    pub audience_id: Option<String>,
+    #[serde(default)]
+    pub num_received: u64,
+    #[serde(default)]
+    pub num_invalid_entries: u64,
+    /// Rejected values (hashed) with the reason Graph gives.
+    pub invalid_entry_samples: Option<serde_json::Value>,
+}
+
+/// Outcome of sending users to an audience, one response per batch of up to
+/// [`MAX_USERS_PER_BATCH`] rows.
+#[derive(Debug, Clone)]
+pub struct AudienceUsersUpload {
+    pub session_id: u64,
+    pub batches: Vec<AudienceUsersResponse>,
+    /// Values rejected locally, which were sent empty.
+    pub rejected: Vec<RejectedValue>,
+    /// Rows left with no valid value, which were not sent.
+    pub skipped_rows: Vec<usize>,
+}
+
+impl AudienceUsersUpload {
+    pub fn num_received(&self) -> u64 {
+        self.batches.iter().map(|batch| batch.num_received).sum()
+    }
+
+    pub fn num_invalid_entries(&self) -> u64 {
+        self.batches.iter().map(|batch| batch.num_invalid_entries).sum()
+    }
+}
+
+fn describe_status(status: Option<&CustomAudienceStatus>) -> String {
+    match status {
+        Some(status) => format!("{} {}", status.code, status.description.as_deref().unwrap_or_default()),
+        None => "unknown".to_string(),
+    }
+}
+
+fn new_session_id() -> u64 {
+    let nanos = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|elapsed| elapsed.as_nanos())
+        .unwrap_or_default();
+    // Graph reads session IDs as signed 64-bit integers.
+    (nanos as u64) & i64::MAX as u64
+}
+
+impl FacebookClient {
+    /// Fetches the first page of custom audiences of an ad account.
+    pub async fn get_custom_audiences(&self, ad_account_id: &str) -> Result<CustomAudiencesResponse, FacebookError> {
+        self.execute(Self::custom_audiences_request(ad_account_id)).await
+    }
+
+    /// Iterates over every custom audience of an ad account, following pagination.
+    pub fn custom_audiences(&self, ad_account_id: &str, params: &ListParams) -> Paginator<'_, CustomAudience> {
+        Paginator::new(self, Self::custom_audiences_request(ad_account_id), params)
+    }
+
+    pub(crate) fn custom_audiences_request(ad_account_id: &str) -> GraphRequest {
+        GraphRequest::get(format!("act_{}/customaudiences", ad_account_id))
+            .fields(&Fields::<CustomAudienceField>::default_set())
+    }
+
+    /// Fetches one custom audience with the given fields.
+    pub async fn get_custom_audience(
+        &self,
+        audience_id: &str,
+        fields: &Fields<CustomAudienceField>,
+    ) -> Result<CustomAudience, FacebookError> {
+        self.execute(Self::custom_audience_request(audience_id, fields)).await
+    }
+
+    pub(crate) fn custom_audience_request(audience_id: &str, fields: &Fields<CustomAudienceField>) -> GraphRequest {
+        GraphRequest::get(audience_id).fields(fields)
+    }
+
+    /// Creates a custom audience in an ad account and returns its ID.
+    ///
+    /// The request is validated locally first; see [`CustomAudienceCreate::validate`].
+    pub async fn create_custom_audience(
+        &self,
+        ad_account_id: &str,
+        audience: CustomAudienceCreate,
+    ) -> Result<String, FacebookError> {
+        let created: CreatedObject = self
+            .execute(Self::create_custom_audience_request(ad_account_id, &audience)?)
+            .await?;
+        Ok(created.id)
+    }
+
+    pub(crate) fn create_custom_audience_request(
+        ad_account_id: &str,
+        audience: &CustomAudienceCreate,
+    ) -> Result<GraphRequest, FacebookError> {
+        audience.validate()?;
+        let mut request = GraphRequest::post(format!("act_{}/customaudiences", ad_account_id));
+        for (key, value) in audience.to_form() {
+            request = request.form(&key, value);
+        }
+        Ok(request)
+    }
+
+    /// Applies an update to a custom audience and returns the audience as Graph reports
+    /// it afterwards, limited to `id` and the fields that were changed.
+    pub async fn update_custom_audience(
+        &self,
+        audience_id: &str,
+        update: CustomAudienceUpdate,
+    ) -> Result<CustomAudience, FacebookError> {
+        self.execute_success(Self::update_custom_audience_request(audience_id, &update)?)
+            .await?;
+        let fields = update.changed_fields().field(CustomAudienceField::Id);
+        self.get_custom_audience(audience_id, &fields).await
+    }
+
+    pub(crate) fn update_custom_audience_request(
+        audience_id: &str,
+        update: &CustomAudienceUpdate,
+    ) -> Result<GraphRequest, FacebookError> {
+        update.validate()?;
+        let mut request = GraphRequest::post(audience_id).idempotent(true);
+        for (key, value) in update.to_form() {
+            request = request.form(&key, value);
+        }
+        Ok(request)
+    }
+
+    pub async fn delete_custom_audience(&self, audience_id: &str) -> Result<(), FacebookError> {
+        self.execute_success(Self::delete_custom_audience_request(audience_id)).await
+    }
+
+    pub(crate) fn delete_custom_audience_request(audience_id: &str) -> GraphRequest {
+        GraphRequest::delete(audience_id)
+    }
+
+    /// Creates a lookalike audience in an ad account and returns its ID.
+    ///
+    /// The request is validated locally first; see [`LookalikeAudienceCreate::validate`].
+    /// Graph builds the audience in the background; see [`Self::wait_for_audience`].
+    pub async fn create_lookalike_audience(
+        &self,
+        ad_account_id: &str,
+        lookalike: LookalikeAudienceCreate,
+    ) -> Result<String, FacebookError> {
+        let created: CreatedObject = self
+            .execute(Self::create_lookalike_audience_request(ad_account_id, &lookalike)?)
+            .await?;
+        Ok(created.id)
+    }
+
+    pub(crate) fn create_lookalike_audience_request(
+        ad_account_id: &str,
+        lookalike: &LookalikeAudienceCreate,
+    ) -> Result<GraphRequest, FacebookError> {
+        lookalike.validate()?;
+        let mut request = GraphRequest::post(format!("act_{}/customaudiences", ad_account_id));
+        for (key, value) in lookalike.to_form() {
+            request = request.form(&key, value);
+        }
+        Ok(request)
+    }
+
+    /// Reads Graph's estimate of the size of any custom or lookalike audience.
+    pub async fn get_approximate_count(&self, audience_id: &str) -> Result<ApproximateCount, FacebookError> {
+        let fields = Fields::new().with(&[
+            CustomAudienceField::Id,
+            CustomAudienceField::ApproximateCountLowerBound,
+            CustomAudienceField::ApproximateCountUpperBound,
+        ]);
+        let audience = self.get_custom_audience(audience_id, &fields).await?;
+        match (audience.approximate_count_lower_bound, audience.approximate_count_upper_bound) {
+            (Some(lower_bound), Some(upper_bound)) => Ok(ApproximateCount { lower_bound, upper_bound }),
+            _ => Err(FacebookError::Unexpected(format!(
+                "Graph returned no approximate count for audience {}",
+                audience_id
+            ))),
+        }
+    }
+
+    /// Polls an audience's `operation_status` and `delivery_status` until it is ready
+    /// for delivery, and returns it with those fields and its approximate count.
+    ///
+    /// Fails with `Unexpected`, quoting the statuses, as soon as [`CustomAudience::has_failed`],
+    /// or when it is not ready after `timeout`.
+    pub async fn wait_for_audience(
+        &self,
+        audience_id: &str,
+        poll_interval: Duration,
+        timeout: Duration,
+    ) -> Result<CustomAudience, FacebookError> {
+        let fields = Fields::new().with(&[
+            CustomAudienceField::Id,
+            CustomAudienceField::OperationStatus,
+            CustomAudienceField::DeliveryStatus,
+            CustomAudienceField::ApproximateCountLowerBound,
+            CustomAudienceField::ApproximateCountUpperBound,
+        ]);
+        let deadline = Instant::now() + timeout;
+        loop {
+            let audience = self.get_custom_audience(audience_id, &fields).await?;
+            if audience.is_ready() {
+                return Ok(audience);
+            }
+            if audience.has_failed() {
+                return Err(FacebookError::Unexpected(format!(
+                    "Audience {} cannot be used (operation: {}, delivery: {})",
+                    audience_id,
+                    describe_status(audience.operation_status.as_ref()),
+                    describe_status(audience.delivery_status.as_ref())
+                )));
+            }
+            if Instant::now() + poll_interval > deadline {
+                return Err(FacebookError::Unexpected(format!(
+                    "Audience {} was not ready after {:?} (operation: {}, delivery: {})",
+                    audience_id,
+                    timeout,
+                    describe_status(audience.operation_status.as_ref()),
+                    describe_status(audience.delivery_status.as_ref())
+                )));
+            }
+            tokio::time::sleep(poll_interval).await;
+        }
+    }
+
+    /// Adds users to a customer list audience, in batches of [`MAX_USERS_PER_BATCH`] rows
+    /// sharing one session.
+    ///
+    /// Every row is normalized and hashed before the first batch is sent. Malformed values
+    /// are sent empty and rows left without a value are dropped; both are listed in the
+    /// returned [`AudienceUsersUpload`] rather than failing the upload.
+    pub async fn add_audience_users(
+        &self,
+        audience_id: &str,
+        users: &AudienceUsers,
+    ) -> Result<AudienceUsersUpload, FacebookError> {
+        self.send_audience_users(Method::POST, format!("{}/users", audience_id), users)
+            .await
+    }
+
+    /// Removes users from a customer list audience, batched like [`Self::add_audience_users`].
+    pub async fn remove_audience_users(
+        &self,
+        audience_id: &str,
+        users: &AudienceUsers,
+    ) -> Result<AudienceUsersUpload, FacebookError> {
+        self.send_audience_users(Method::DELETE, format!("{}/users", audience_id), users)
+            .await
+    }
+
+    /// Replaces every user of a customer list audience with `users`.
+    ///
+    /// Graph starts the replace with the first batch of the session and swaps the audience's
+    /// users once the batch flagged as last arrives. The audience keeps delivering in the
+    /// meantime. If the call fails midway, send the same users again with the session ID
+    /// of the failed upload, within 90 minutes of its first batch, to complete it.
+    pub async fn replace_audience_users(
+        &self,
+        audience_id: &str,
+        users: &AudienceUsers,
+    ) -> Result<AudienceUsersUpload, FacebookError> {
+        self.send_audience_users(Method::POST, format!("{}/usersreplace", audience_id), users)
+            .await
+    }
+
+    async fn send_audience_users(
+        &self,
+        method: Method,
+        path: String,
+        users: &AudienceUsers,
+    ) -> Result<AudienceUsersUpload, FacebookError> {
+        let prepared = users.prepared_rows()?;
+        let (session_id, requests) = Self::audience_users_requests(method, &path, users, &prepared.rows);
+        let mut batches = Vec::with_capacity(requests.len());
+        for request in requests {
+            batches.push(self.execute(request).await?);
+        }
+        Ok(AudienceUsersUpload {
+            session_id,
+            batches,
+            rejected: prepared.rejected,
+            skipped_rows: prepared.skipped_rows,
+        })
+    }
+
+    pub(crate) fn audience_users_requests(
+        method: Method,
+        path: &str,
+        users: &AudienceUsers,
+        rows: &[Vec<String>],
+    ) -> (u64, Vec<GraphRequest>) {
+        let session_id = users.session_id.unwrap_or_else(new_session_id);
+        let chunks = rows.chunks(MAX_USERS_PER_BATCH).count();
+        let requests = rows
+            .chunks(MAX_USERS_PER_BATCH)
+            .enumerate()
+            .map(|(i, data)| {
+                let payload = UsersPayload { schema: &users.schema, data };
+                let session = UsersSession {
+                    session_id,
+                    batch_seq: i + 1,
+                    last_batch_flag: i + 1 == chunks,
+                    estimated_num_total: rows.len(),
+                };
+                // Sending the same users twice leaves the audience as it was, so a batch may be retried.
+                GraphRequest::new(method.clone(), path)
+                    .form("payload", to_json(&payload))
+                    .form("session", to_json(&session))
+                    .idempotent(true)
+            })
+            .collect();
+        (session_id, requests)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const JANE_EMAIL_SHA256: &str = "86e0b9e56c17cc4d12387e1949b85053fbe73bc3ce5a1188713a9d300cc6133d";
+    const JANE_PHONE_SHA256: &str = "e323ec626319ca94ee8bff2e4c87cf613be6ea19919ed1364124e16807ab3176";
+
+    #[test]
+    fn email_is_trimmed_lowercased_and_hashed() {
+        assert_eq!(MatchKey::Email.normalize(" Jane.Doe@Example.com ").unwrap(), "jane.doe@example.com");
+        assert_eq!(MatchKey::Email.prepare(" Jane.Doe@Example.com ").unwrap(), JANE_EMAIL_SHA256);
+        assert!(MatchKey::Email.prepare("jane.doe").is_err());
+    }
+
+    #[test]
+    fn phone_keeps_digits_after_plus_or_00() {
+        for raw in ["+1 (650) 555-1212", "001 650 555 1212", "1-650-555-1212"] {
+            assert_eq!(MatchKey::Phone.normalize(raw).unwrap(), "16505551212", "{}", raw);
+            assert_eq!(MatchKey::Phone.prepare(raw).unwrap(), JANE_PHONE_SHA256, "{}", raw);
+        }
+    }
+
+    #[test]
+    fn phone_rejects_trunk_prefix_and_bad_lengths() {
+        assert!(MatchKey::Phone.normalize("0650 555 1212").is_err());
+        assert!(MatchKey::Phone.normalize("00 0650 555 1212").is_err());
+        assert!(MatchKey::Phone.normalize("+1 555 12").is_err());
+        assert!(MatchKey::Phone.normalize("+1 650 555 1212 1234 5").is_err());
+    }
+
+    #[test]
+    fn empty_values_stay_empty_and_unhashed() {
+        assert_eq!(MatchKey::Email.prepare("  ").unwrap(), "");
+        assert_eq!(MatchKey::Phone.prepare("").unwrap(), "");
+    }
+
+    #[test]
+    fn mobile_advertiser_id_is_sent_unhashed() {
+        let madid = " 6D92078A-8246-4BA4-AE5B-76104861E7DC ";
+        assert_eq!(
+            MatchKey::MobileAdvertiserId.prepare(madid).unwrap(),
+            "6d92078a-8246-4ba4-ae5b-76104861e7dc"
+        );
+    }
+
+    #[test]
+    fn names_zips_and_countries_are_normalized() {
+        assert_eq!(MatchKey::FirstName.normalize(" Mary-Jane ").unwrap(), "maryjane");
+        assert_eq!(MatchKey::Zip.normalize("94025-1234").unwrap(), "94025");
+        assert_eq!(MatchKey::Country.normalize(" US ").unwrap(), "us");
+        assert!(MatchKey::Country.normalize("USA").is_err());
+    }
+
+    #[test]
+    fn prepared_rows_send_malformed_values_empty_and_skip_empty_rows() {
+        let users = AudienceUsers::new(&[MatchKey::Email, MatchKey::Phone])
+            .row(&["Jane.Doe@Example.com", "0650 555 1212"])
+            .row(&["not an email", "123"])
+            .row(&["", "+1 650 555 1212"]);
+        let prepared = users.prepared_rows().unwrap();
+        assert_eq!(
+            prepared.rows,
+            vec![
+                vec![JANE_EMAIL_SHA256.to_string(), String::new()],
+                vec![String::new(), JANE_PHONE_SHA256.to_string()],
+            ]
+        );
+        assert_eq!(prepared.skipped_rows, vec![1]);
+        let rejected: Vec<(usize, MatchKey)> = prepared.rejected.iter().map(|value| (value.row, value.key)).collect();
+        assert_eq!(
+            rejected,
+            vec![(0, MatchKey::Phone), (1, MatchKey::Email), (1, MatchKey::Phone)]
+        );
+    }
+
+    #[test]
+    fn prepared_rows_fail_when_no_row_is_usable() {
+        let users = AudienceUsers::new(&[MatchKey::Phone]).row(&["0650 555 1212"]);
+        assert!(matches!(users.prepared_rows(), Err(FacebookError::InvalidRequest(_))));
+    }
+}
@@ -0,0 +1,633 @@
+//! Graph Batch API: up to 50 calls in one HTTP request.
+//!
+//! See <https://developers.facebook.com/docs/graph-api/batch-requests>.
+
+use crate::ad::{AdCreate, AdUpdate};
+use crate::adset::{AdSetCreate, AdSetUpdate};
+use crate::audience::{CustomAudienceCreate, CustomAudienceUpdate, LookalikeAudienceCreate};
+use crate::campaign::{CampaignCreate, CampaignUpdate};
+use crate::client::{FacebookClient, GraphRequest};
+use crate::creative::AdCreativeCreate;
+use crate::errors::{FacebookError, GraphApiError};
+use crate::fields::{AdField, AdSetField, CampaignField, CustomAudienceField, Fields};
+use reqwest::Method;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+
+/// Graph's limit on the number of calls in one batch.
+pub const MAX_BATCH_SIZE: usize = 50;
+
+/// One call of a batch.
+#[derive(Serialize, Debug, Clone)]
+pub struct BatchRequest {
+    pub method: String,
+    /// Path and query string, relative to the versioned Graph root.
+    pub relative_url: String,
+    /// URL-encoded form body of POST calls.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub body: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub depends_on: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub omit_response_on_success: Option<bool>,
+}
+
+impl BatchRequest {
+    fn new(method: Method, relative_url: impl Into<String>) -> Self {
+        BatchRequest {
+            method: method.as_str().to_string(),
+            relative_url: relative_url.into(),
+            body: None,
+            name: None,
+            depends_on: None,
+            omit_response_on_success: None,
+        }
+    }
+
+    /// A GET call. `relative_url` is sent as is, so it may contain JSONPath references.
+    pub fn get(relative_url: impl Into<String>) -> Self {
+        Self::new(Method::GET, relative_url)
+    }
+
+    /// A POST call with a URL-encoded body.
+    pub fn post(relative_url: impl Into<String>, params: &[(&str, &str)]) -> Self {
+        let mut request = Self::new(Method::POST, relative_url);
+        request.body = Some(encode_pairs(params.iter().copied()));
+        request
+    }
+
+    pub fn delete(relative_url: impl Into<String>) -> Self {
+        Self::new(Method::DELETE, relative_url)
+    }
+
+    /// Names the call so that later calls can depend on it or reference its result.
+    pub fn name(mut self, name: &str) -> Self {
+        self.name = Some(name.to_string());
+        self
+    }
+
+    /// Runs this call only after the named call succeeded.
+    pub fn depends_on(mut self, name: &str) -> Self {
+        self.depends_on = Some(name.to_string());
+        self
+    }
+
+    /// Leaves the response of a successful call out of the batch result.
+    ///
+    /// Graph omits by default the responses of calls that other calls reference.
+    pub fn omit_response_on_success(mut self, omit: bool) -> Self {
+        self.omit_response_on_success = Some(omit);
+        self
+    }
+
+    pub fn get_ad_accounts() -> Self {
+        Self::from(FacebookClient::ad_accounts_request())
+    }
+
+    pub fn get_campaigns(ad_account_id: &str) -> Self {
+        Self::from(FacebookClient::campaigns_request(ad_account_id))
+    }
+
+    pub fn get_ad_sets(campaign_id: &str) -> Self {
+        Self::from(FacebookClient::ad_sets_request(campaign_id))
+    }
+
+    pub fn get_ad_set(ad_set_id: &str, fields: &Fields<AdSetField>) -> Self {
+        Self::from(FacebookClient::ad_set_request(ad_set_id, fields))
+    }
+
+    /// Fails when the ad set does not pass [`AdSetCreate::validate`].
+    pub fn create_ad_set(ad_account_id: &str, ad_set: &AdSetCreate) -> Result<Self, FacebookError> {
+        FacebookClient::create_ad_set_request(ad_account_id, ad_set).map(Self::from)
+    }
+
+    /// Fails when the update does not pass [`AdSetUpdate::validate`].
+    pub fn update_ad_set(ad_set_id: &str, update: &AdSetUpdate) -> Result<Self, FacebookError> {
+        FacebookClient::update_ad_set_request(ad_set_id, update).map(Self::from)
+    }
+
+    pub fn get_ads(ad_set_id: &str) -> Self {
+        Self::from(FacebookClient::ads_request(ad_set_id))
+    }
+
+    pub fn get_campaign(campaign_id: &str, fields: &Fields<CampaignField>) -> Self {
+        Self::from(FacebookClient::campaign_request(campaign_id, fields))
+    }
+
+    /// Fails when the campaign does not pass [`CampaignCreate::validate`].
+    pub fn create_campaign(ad_account_id: &str, campaign: &CampaignCreate) -> Result<Self, FacebookError> {
+        FacebookClient::create_campaign_request(ad_account_id, campaign).map(Self::from)
+    }
+
+    /// Fails when the update does not pass [`CampaignUpdate::validate`].
+    pub fn update_campaign(campaign_id: &str, update: &CampaignUpdate) -> Result<Self, FacebookError> {
+        FacebookClient::update_campaign_request(campaign_id, update).map(Self::from)
+    }
+
+    pub fn get_ad(ad_id: &str, fields: &Fields<AdField>) -> Self {
+        Self::from(FacebookClient::ad_request(ad_id, fields))
+    }
+
+    /// Fails when the ad does not pass [`AdCreate::validate`].
+    pub fn create_ad(ad_account_id: &str, ad: &AdCreate) -> Result<Self, FacebookError> {
+        FacebookClient::create_ad_request(ad_account_id, ad).map(Self::from)
+    }
+
+    /// Fails when the update does not pass [`AdUpdate::validate`].
+    pub fn update_ad(ad_id: &str, update: &AdUpdate) -> Result<Self, FacebookError> {
+        FacebookClient::update_ad_request(ad_id, update).map(Self::from)
+    }
+
+    pub fn delete_ad(ad_id: &str) -> Self {
+        Self::from(FacebookClient::delete_ad_request(ad_id))
+    }
+
+    pub fn get_creative(creative_id: &str) -> Self {
+        Self::from(FacebookClient::creative_request(creative_id))
+    }
+
+    /// Fails when the creative does not pass [`AdCreativeCreate::validate`].
+    pub fn create_ad_creative(ad_account_id: &str, creative: &AdCreativeCreate) -> Result<Self, FacebookError> {
+        FacebookClient::create_ad_creative_request(ad_account_id, creative).map(Self::from)
+    }
+
+    pub fn activate_campaign(campaign_id: &str) -> Self {
+        Self::from(FacebookClient::activate_campaign_request(campaign_id))
+    }
+
+    pub fn delete_campaign(campaign_id: &str) -> Self {
+        Self::from(FacebookClient::delete_campaign_request(campaign_id))
+    }
+
+    pub fn get_custom_audiences(ad_account_id: &str) -> Self {
+        Self::from(FacebookClient::custom_audiences_request(ad_account_id))
+    }
+
+    pub fn get_custom_audience(audience_id: &str, fields: &Fields<CustomAudienceField>) -> Self {
+        Self::from(FacebookClient::custom_audience_request(audience_id, fields))
+    }
+
+    /// Fails when the audience does not pass [`CustomAudienceCreate::validate`].
+    pub fn create_custom_audience(ad_account_id: &str, audience: &CustomAudienceCreate) -> Result<Self, FacebookError> {
+        FacebookClient::create_custom_audience_request(ad_account_id, audience).map(Self::from)
+    }
+
+    /// Fails when the update does not pass [`CustomAudienceUpdate::validate`].
+    pub fn update_custom_audience(audience_id: &str, update: &CustomAudienceUpdate) -> Result<Self, FacebookError> {
+        FacebookClient::update_custom_audience_request(audience_id, update).map(Self::from)
+    }
+
+    /// Fails when the lookalike does not pass [`LookalikeAudienceCreate::validate`].
+    pub fn create_lookalike_audience(ad_account_id: &str, lookalike: &LookalikeAudienceCreate) -> Result<Self, FacebookError> {
+        FacebookClient::create_lookalike_audience_request(ad_account_id, lookalike).map(Self::from)
+    }
+
+    pub fn delete_custom_audience(audience_id: &str) -> Self {
+        Self::from(FacebookClient::delete_custom_audience_request(audience_id))
+    }
+
+    /// Names of the calls this one needs, through `depends_on` or a JSONPath reference.
+    fn dependencies(&self) -> Vec<&str> {
+        let mut names: Vec<&str> = self.depends_on.as_deref().into_iter().collect();
+        for text in [Some(self.relative_url.as_str()), self.body.as_deref()].into_iter().flatten() {
+            names.extend(referenced_names(text));
+        }
+        names
+    }
+}
+
+impl From<GraphRequest> for BatchRequest {
+    fn from(request: GraphRequest) -> Self {
+        let mut relative_url = request.path.trim_start_matches('/').to_string();
+        if !request.query.is_empty() {
+            relative_url.push('?');
+            relative_url.push_str(&encode_pairs(
+                request.query.iter().map(|(key, value)| (key.as_str(), value.as_str())),
+            ));
+        }
+        let mut batch_request = Self::new(request.method, relative_url);
+        if !request.form.is_empty() {
+            batch_request.body = Some(encode_pairs(
+                request.form.iter().map(|(key, value)| (key.as_str(), value.as_str())),
+            ));
+        }
+        batch_request
+    }
+}
+
+/// A JSONPath reference to the result of a named call, e.g.
+/// `result_ref("campaigns", "$.data.*.id")` gives `{result=campaigns:$.data.*.id}`.
+pub fn result_ref(name: &str, json_path: &str) -> String {
+    format!("{{result={}:{}}}", name, json_path)
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct BatchHeader {
+    pub name: String,
+    pub value: String,
+}
+
+/// A successful call of a batch.
+#[derive(Debug, Clone)]
+pub struct BatchResponse {
+    pub code: u16,
+    pub headers: Vec<BatchHeader>,
+    /// JSON body, `None` when Graph omitted it. An omitted response has `code` 200 and
+    /// no headers.
+    pub body: Option<String>,
+}
+
+impl BatchResponse {
+    /// Decodes the body into the type the equivalent direct call returns.
+    pub fn decode<T: DeserializeOwned>(&self) -> Result<T, FacebookError> {
+        let body = self
+            .body
+            .as_deref()
+            .ok_or_else(|| FacebookError::Unexpected("Batch response body was omitted".to_string()))?;
+        serde_json::from_str(body)
+            .map_err(|e| FacebookError::Unexpected(format!("Failed to parse JSON: {}", e)))
+    }
+}
+
+#[derive(Deserialize, Debug)]
+struct RawBatchResponse {
+    code: u16,
+    #[serde(default)]
+    headers: Vec<BatchHeader>,
+    body: Option<String>,
+}
+
+impl RawBatchResponse {
+    fn into_result(self) -> Result<BatchResponse, FacebookError> {
+        if (200..300).contains(&self.code) {
+            return Ok(BatchResponse {
+                code: self.code,
+                headers: self.headers,
+                body: self.body,
+            });
+        }
+        let body = self.body.unwrap_or_default();
+        match GraphApiError::from_response(self.code, &body) {
+            Some(error) => Err(FacebookError::GraphApiError(Box::new(error))),
+            None => Err(FacebookError::HttpError(format!(
+                "Unexpected response status: {}. Response: {}",
+                self.code, body
+            ))),
+        }
+    }
+}
+
+impl FacebookClient {
+    /// Sends calls through the Batch API and returns one result per call, in order.
+    ///
+    /// More than [`MAX_BATCH_SIZE`] calls are split into several batches. Calls linked
+    /// by `depends_on` or JSONPath references are kept in the same batch; a linked
+    /// group larger than the limit is rejected.
+    ///
+    /// A call whose response was omitted, with `omit_response_on_success` or because a
+    /// later call references it, succeeds with no `body`. Any other call Graph did not
+    /// run, because a call it depends on failed, is reported as `Unexpected`. If sending
+    /// a batch fails, its calls carry that error and the calls of later batches are
+    /// reported as not sent; the results of batches already sent are kept.
+    pub async fn batch(
+        &self,
+        requests: Vec<BatchRequest>,
+    ) -> Result<Vec<Result<BatchResponse, FacebookError>>, FacebookError> {
+        let total = requests.len();
+        let mut results: Vec<Option<Result<BatchResponse, FacebookError>>> = (0..total).map(|_| None).collect();
+
+        let mut failure: Option<FacebookError> = None;
+        for chunk in chunk_requests(requests)? {
+            let (indices, chunk): (Vec<usize>, Vec<BatchRequest>) = chunk.into_iter().unzip();
+            if let Some(error) = &failure {
+                for index in indices {
+                    results[index] = Some(Err(FacebookError::Unexpected(format!(
+                        "Batch call was not sent because an earlier batch failed: {}",
+                        error
+                    ))));
+                }
+                continue;
+            }
+            match self.send_batch(&chunk).await {
+                Ok(responses) => {
+                    for (index, result) in indices.into_iter().zip(chunk_results(&chunk, responses)) {
+                        results[index] = Some(result);
+                    }
+                }
+                Err(error) => {
+                    for index in indices {
+                        results[index] = Some(Err(error.clone()));
+                    }
+                    failure = Some(error);
+                }
+            }
+        }
+
+        Ok(results
+            .into_iter()
+            .map(|result| {
+                result.unwrap_or_else(|| {
+                    Err(FacebookError::Unexpected("Batch response is missing a result".to_string()))
+                })
+            })
+            .collect())
+    }
+
+    async fn send_batch(&self, chunk: &[BatchRequest]) -> Result<Vec<Option<RawBatchResponse>>, FacebookError> {
+        let batch = serde_json::to_string(chunk)
+            .map_err(|e| FacebookError::Unexpected(format!("Failed to encode batch: {}", e)))?;
+        let read_only = chunk.iter().all(|request| request.method == Method::GET.as_str());
+        let request = GraphRequest::post("").form("batch", batch).idempotent(read_only);
+        let responses: Vec<Option<RawBatchResponse>> = self.execute(request).await?;
+        if responses.len() != chunk.len() {
+            return Err(FacebookError::Unexpected(format!(
+                "Batch returned {} results for {} calls",
+                responses.len(),
+                chunk.len()
+            )));
+        }
+        Ok(responses)
+    }
+}
+
+/// Turns the responses of one batch into per-call results.
+///
+/// Graph answers `null` both for a call whose response was omitted and for a call it
+/// skipped. A call may have been omitted if `omit_response_on_success` was set or, when
+/// left unset, if a later call references it; it was skipped if a call it needs failed.
+fn chunk_results(
+    chunk: &[BatchRequest],
+    responses: Vec<Option<RawBatchResponse>>,
+) -> Vec<Result<BatchResponse, FacebookError>> {
+    let referenced: HashSet<&str> = chunk.iter().flat_map(BatchRequest::dependencies).collect();
+    let mut failed: HashSet<&str> = HashSet::new();
+    let mut results = Vec::with_capacity(chunk.len());
+    for (request, response) in chunk.iter().zip(responses) {
+        let result = match response {
+            Some(response) => response.into_result(),
+            None => {
+                let may_omit = request
+                    .omit_response_on_success
+                    .unwrap_or_else(|| request.name.as_deref().is_some_and(|name| referenced.contains(name)));
+                let skipped = request.dependencies().iter().any(|name| failed.contains(name));
+                if may_omit && !skipped {
+                    Ok(BatchResponse {
+                        code: 200,
+                        headers: Vec::new(),
+                        body: None,
+                    })
+                } else {
+                    Err(FacebookError::Unexpected(
+                        "Batch call was not executed because a call it depends on failed".to_string(),
+                    ))
+                }
+            }
+        };
+        if let (Err(_), Some(name)) = (&result, request.name.as_deref()) {
+            failed.insert(name);
+        }
+        results.push(result);
+    }
+    results
+}
+
+/// Splits calls into batches of at most [`MAX_BATCH_SIZE`], keeping linked calls together
+/// and each call's original index.
+fn chunk_requests(requests: Vec<BatchRequest>) -> Result<Vec<Vec<(usize, BatchRequest)>>, FacebookError> {
+    // Union-find over call indices, joining each call with the calls it needs.
+    let mut parent: Vec<usize> = (0..requests.len()).collect();
+    fn root(parent: &mut [usize], mut index: usize) -> usize {
+        while parent[index] != index {
+            parent[index] = parent[parent[index]];
+            index = parent[index];
+        }
+        index
+    }
+
+    let mut by_name: HashMap<&str, usize> = HashMap::new();
+    for (index, request) in requests.iter().enumerate() {
+        for dependency in request.dependencies() {
+            let target = *by_name.get(dependency).ok_or_else(|| {
+                FacebookError::Unexpected(format!(
+                    "Batch call depends on \"{}\", which is not an earlier named call",
+                    dependency
+                ))
+            })?;
+            let (a, b) = (root(&mut parent, index), root(&mut parent, target));
+            parent[a] = b;
+        }
+        if let Some(name) = request.name.as_deref() {
+            by_name.insert(name, index);
+        }
+    }
+
+    let mut groups: Vec<(usize, Vec<usize>)> = Vec::new();
+    let mut group_of_root: HashMap<usize, usize> = HashMap::new();
+    for index in 0..requests.len() {
+        let group_root = root(&mut parent, index);
+        let group = *group_of_root.entry(group_root).or_insert_with(|| {
+            groups.push((group_root, Vec::new()));
+            groups.len() - 1
+        });
+        groups[group].1.push(index);
+    }
+
+    let mut slots: Vec<Option<BatchRequest>> = requests.into_iter().map(Some).collect();
+    let mut chunks: Vec<Vec<(usize, BatchRequest)>> = Vec::new();
+    let mut current: Vec<(usize, BatchRequest)> = Vec::new();
+    for (_, members) in groups {
+        if members.len() > MAX_BATCH_SIZE {
+            return Err(FacebookError::Unexpected(format!(
+                "{} linked batch calls exceed the limit of {} per batch",
+                members.len(),
+                MAX_BATCH_SIZE
+            )));
+        }
+        if current.len() + members.len() > MAX_BATCH_SIZE {
+            chunks.push(std::mem::take(&mut current));
+        }
+        for index in members {
+            if let Some(request) = slots[index].take() {
+                current.push((index, request));
+            }
+        }
+    }
+    if !current.is_empty() {
+        chunks.push(current);
+    }
+    // Members of a group were pushed in index order, but groups may interleave.
+    for chunk in &mut chunks {
+        chunk.sort_by_key(|(index, _)| *index);
+    }
+    Ok(chunks)
+}
+
+/// Names referenced as `{result=NAME:...}` in a URL or body.
+fn referenced_names(text: &str) -> Vec<&str> {
+    text.match_indices("{result=")
+        .filter_map(|(start, marker)| {
+            let rest = &text[start + marker.len()..];
+            rest.find(':').map(|end| &rest[..end])
+        })
+        .collect()
+}
+
+/// URL-encodes key/value pairs, leaving JSONPath references readable for Graph.
+fn encode_pairs<'a>(pairs: impl Iterator<Item = (&'a str, &'a str)>) -> String {
+    pairs
+        .map(|(key, value)| format!("{}={}", encode_component(key), encode_component(value)))
+        .collect::<Vec<_>>()
+        .join("&")
+}
+
+fn encode_component(text: &str) -> String {
+    let mut encoded = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(start) = rest.find("{result=") {
+        let (before, reference) = rest.split_at(start);
+        encoded.push_str(&percent_encode(before));
+        match reference.find('}') {
+            Some(end) => {
+                encoded.push_str(&reference[..=end]);
+                rest = &reference[end + 1..];
+            }
+            None => {
+                encoded.push_str(&percent_encode(reference));
+                rest = "";
+            }
+        }
+    }
+    encoded.push_str(&percent_encode(rest));
+    encoded
+}
+
+fn percent_encode(text: &str) -> String {
+    let mut encoded = String::with_capacity(text.len());
+    for byte in text.bytes() {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' | b',' => {
+                encoded.push(byte as char)
+            }
+            _ => encoded.push_str(&format!("%{:02X}", byte)),
+        }
+    }
+    encoded
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn reads(count: usize) -> Vec<BatchRequest> {
+        (0..count).map(|i| BatchRequest::get(format!("{}", i))).collect()
+    }
+
+    fn chunk_indices(requests: Vec<BatchRequest>) -> Vec<Vec<usize>> {
+        chunk_requests(requests)
+            .unwrap()
+            .into_iter()
+            .map(|chunk| chunk.into_iter().map(|(index, _)| index).collect())
+            .collect()
+    }
+
+    fn response(code: u16, body: &str) -> Option<RawBatchResponse> {
+        Some(RawBatchResponse {
+            code,
+            headers: Vec::new(),
+            body: Some(body.to_string()),
+        })
+    }
+
+    #[test]
+    fn fifty_calls_fit_one_batch() {
+        assert_eq!(chunk_indices(reads(50)), vec![(0..50).collect::<Vec<_>>()]);
+    }
+
+    #[test]
+    fn fifty_one_calls_split_at_the_limit() {
+        assert_eq!(chunk_indices(reads(51)), vec![(0..50).collect::<Vec<_>>(), vec![50]]);
+    }
+
+    #[test]
+    fn dependency_across_the_limit_moves_to_the_next_batch() {
+        let mut requests = reads(49);
+        requests.push(BatchRequest::get("act_1/campaigns").name("campaigns"));
+        requests.push(BatchRequest::get("ads").depends_on("campaigns"));
+        assert_eq!(chunk_indices(requests), vec![(0..49).collect::<Vec<_>>(), vec![49, 50]]);
+    }
+
+    #[test]
+    fn result_reference_links_calls_and_keeps_their_order() {
+        let mut requests = vec![BatchRequest::get("act_1/campaigns").name("campaigns")];
+        requests.extend(reads(49));
+        requests.push(BatchRequest::get(format!("?ids={}", result_ref("campaigns", "$.data.*.id"))));
+        // The linked pair is placed first, so the last unlinked call is the one that overflows.
+        let mut first = (0..49).collect::<Vec<_>>();
+        first.push(50);
+        assert_eq!(chunk_indices(requests), vec![first, vec![49]]);
+    }
+
+    #[test]
+    fn linked_group_over_the_limit_is_rejected() {
+        let mut requests = vec![BatchRequest::get("act_1/campaigns").name("campaigns")];
+        requests.extend((0..50).map(|i| BatchRequest::delete(format!("{}", i)).depends_on("campaigns")));
+        assert!(chunk_requests(requests).is_err());
+    }
+
+    #[test]
+    fn unknown_dependency_is_rejected() {
+        assert!(chunk_requests(vec![BatchRequest::get("ads").depends_on("campaigns")]).is_err());
+    }
+
+    #[test]
+    fn encode_pairs_keeps_result_references_readable() {
+        let reference = result_ref("create", "$.id");
+        let body = encode_pairs([("name", "Spring sale & more"), ("campaign_id", reference.as_str())].into_iter());
+        assert_eq!(body, "name=Spring%20sale%20%26%20more&campaign_id={result=create:$.id}");
+    }
+
+    #[test]
+    fn referenced_names_finds_every_reference() {
+        assert_eq!(
+            referenced_names("?ids={result=a:$.data.*.id},{result=b:$.id}"),
+            vec!["a", "b"]
+        );
+    }
+
+    #[test]
+    fn omitted_response_of_referenced_call_is_a_success() {
+        let chunk = vec![
+            BatchRequest::post("act_1/campaigns", &[("name", "Spring")]).name("create"),
+            BatchRequest::get(format!("{}?fields=name", result_ref("create", "$.id"))),
+        ];
+        let results = chunk_results(&chunk, vec![None, response(200, "{\"name\":\"Spring\"}")]);
+        assert!(results[0].as_ref().is_ok_and(|response| response.body.is_none()));
+        assert!(results[1].is_ok());
+    }
+
+    #[test]
+    fn null_after_failed_dependency_is_an_error() {
+        let chunk = vec![
+            BatchRequest::post("act_1/campaigns", &[("name", "Spring")]).name("create"),
+            BatchRequest::post("1/adsets", &[]).name("adset").depends_on("create"),
+            BatchRequest::get(result_ref("adset", "$.id")),
+        ];
+        let error = r#"{"error":{"message":"Invalid parameter","code":100}}"#;
+        let results = chunk_results(&chunk, vec![response(400, error), None, None]);
+        assert!(results.iter().all(Result::is_err));
+    }
+
+    #[test]
+    fn explicit_omission_is_a_success_and_unreferenced_null_is_an_error() {
+        let chunk = vec![
+            BatchRequest::delete("1").omit_response_on_success(true),
+            BatchRequest::delete("2"),
+        ];
+        let results = chunk_results(&chunk, vec![None, None]);
+        assert!(results[0].is_ok());
+        assert!(results[1].is_err());
+    }
+}
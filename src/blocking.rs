@@ -0,0 +1,279 @@
+//! Blocking wrapper around the async [`crate::client::FacebookClient`].
+//!
+//! Every call is driven to completion on a runtime owned by the wrapper, so it
+//! must not be used from inside another `tokio` runtime.
+
+use crate::ad::{AdCreate, AdUpdate};
+use crate::adset::{AdSetCreate, AdSetUpdate};
+use crate::audience::{
+    ApproximateCount, AudienceUsers, AudienceUsersUpload, CustomAudienceCreate, CustomAudienceUpdate, LookalikeAudienceCreate,
+};
+use crate::batch::{BatchRequest, BatchResponse};
+use crate::builder::FacebookClientBuilder;
+use crate::campaign::{CampaignCreate, CampaignUpdate};
+use crate::client;
+use crate::creative::AdCreativeCreate;
+use crate::errors::FacebookError;
+use crate::fields::{AdField, AdSetField, CampaignField, CustomAudienceField, Fields};
+use crate::insights::{AdReportRun, InsightsObject, InsightsParams, InsightsResponse, InsightsRow};
+use crate::media::{AdImage, MediaSource, VideoStatus, VideoUploadSession};
+use crate::pagination::{self, ListParams};
+use crate::retry::RetryPolicy;
+use crate::search::{
+    GeoLocationResult, GeoLocationSearch, InterestResult, InterestValidity, LocaleResult, TargetingCategory,
+    TargetingCategoryClass,
+};
+use crate::secret::Secret;
+use crate::token::{AccessTokenResponse, DebugTokenData, TokenEvent};
+use crate::usage::UsageTracker;
+use crate::types::*;
+use serde::de::DeserializeOwned;
+use tokio::runtime::{Builder, Runtime};
+
+/// Forwards a method of the async client, blocking on the returned future.
+macro_rules! blocking_forward {
+    ($($(#[$meta:meta])* fn $name:ident(&self $(, $arg:ident: $ty:ty)*) -> $ret:ty;)*) => {
+        $(
+            $(#[$meta])*
+            pub fn $name(&self $(, $arg: $ty)*) -> $ret {
+                self.runtime.block_on(self.inner.$name($($arg),*))
+            }
+        )*
+    };
+}
+
+/// Wraps a paginating method of the async client in a blocking [`Paginator`].
+macro_rules! blocking_paginate {
+    ($($(#[$meta:meta])* fn $name:ident(&self $(, $arg:ident: $ty:ty)*) -> $item:ty;)*) => {
+        $(
+            $(#[$meta])*
+            pub fn $name(&self $(, $arg: $ty)*) -> Paginator<'_, $item> {
+                Paginator {
+                    runtime: &self.runtime,
+                    inner: self.inner.$name($($arg),*),
+                }
+            }
+        )*
+    };
+}
+
+#[derive(Debug)]
+pub struct FacebookClient {
+    inner: client::FacebookClient,
+    runtime: Runtime,
+}
+
+impl FacebookClient {
+    /// Creates a new blocking Facebook client and fetches the initial App Access Token.
+    ///
+    /// See [`client::FacebookClient::new`] for the meaning of the arguments.
+    pub fn new(app_id: &str, app_secret: &str, user_token: &str, version: &str) -> Result<Self, FacebookError> {
+        Self::from_builder(client::FacebookClient::builder(app_id, app_secret, user_token).version(version))
+    }
+
+    /// Builds a blocking client from a configured [`FacebookClientBuilder`].
+    pub fn from_builder(builder: FacebookClientBuilder) -> Result<Self, FacebookError> {
+        let runtime = Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .map_err(|e| FacebookError::Unexpected(format!("Failed to start runtime: {}", e)))?;
+        let inner = runtime.block_on(builder.build())?;
+        Ok(FacebookClient { inner, runtime })
+    }
+
+    /// Replaces the retry policy used for every request.
+    pub fn with_retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.inner = self.inner.with_retry_policy(retry_policy);
+        self
+    }
+
+    /// Pauses requests once Graph reports usage at or above `threshold_pct` percent.
+    pub fn with_usage_pause(mut self, threshold_pct: f64) -> Self {
+        self.inner = self.inner.with_usage_pause(threshold_pct);
+        self
+    }
+
+    /// Signs every request with `appsecret_proof`.
+    pub fn with_appsecret_proof(mut self, enabled: bool) -> Self {
+        self.inner = self.inner.with_appsecret_proof(enabled);
+        self
+    }
+
+    /// Calls `handler` whenever a token check runs or a call fails because of its token.
+    pub fn with_token_event_handler(mut self, handler: impl Fn(&TokenEvent) + Send + Sync + 'static) -> Self {
+        self.inner = self.inner.with_token_event_handler(handler);
+        self
+    }
+
+    /// Latest rate-limit usage reported by Graph.
+    pub fn usage(&self) -> &UsageTracker {
+        self.inner.usage()
+    }
+
+    /// Returns the underlying async client.
+    pub fn inner(&self) -> &client::FacebookClient {
+        &self.inner
+    }
+
+    /// Fetches or refreshes the App Access Token for the Facebook application.
+    pub fn refresh_app_access_token(&mut self) -> Result<(), FacebookError> {
+        self.runtime.block_on(self.inner.refresh_app_access_token())
+    }
+
+    /// Replaces the client's user token with a long-lived exchange of it.
+    pub fn upgrade_to_long_lived_token(&mut self) -> Result<AccessTokenResponse, FacebookError> {
+        self.runtime.block_on(self.inner.upgrade_to_long_lived_token())
+    }
+
+    /// Replaces the user token sent with every call.
+    pub fn set_user_token(&mut self, user_token: impl Into<Secret>) {
+        self.inner.set_user_token(user_token);
+    }
+
+    /// Iterates over every row of an insights report, following pagination.
+    pub fn insights(&self, object: InsightsObject<'_>, params: &InsightsParams) -> Result<Paginator<'_, InsightsRow>, FacebookError> {
+        Ok(Paginator {
+            runtime: &self.runtime,
+            inner: self.inner.insights(object, params)?,
+        })
+    }
+
+    blocking_forward! {
+        /// Fetches ad accounts associated with the user.
+        fn get_ad_accounts(&self) -> Result<AdsAccountsResponse, FacebookError>;
+        fn get_campaigns(&self, ad_account_id: &str, params: &ListParams) -> Result<CampaignsResponse, FacebookError>;
+        /// Fetches one campaign with the given fields.
+        fn get_campaign(&self, campaign_id: &str, fields: &Fields<CampaignField>) -> Result<Campaign, FacebookError>;
+        /// Creates a campaign in an ad account and returns its ID.
+        fn create_campaign(&self, ad_account_id: &str, campaign: CampaignCreate) -> Result<String, FacebookError>;
+        /// Applies an update to a campaign and returns its changed fields as Graph reports them.
+        fn update_campaign(&self, campaign_id: &str, update: CampaignUpdate) -> Result<Campaign, FacebookError>;
+        fn activate_campaign(&self, campaign_id: &str) -> Result<(), FacebookError>;
+        fn delete_campaign(&self, campaign_id: &str) -> Result<(), FacebookError>;
+        fn get_ad_sets(&self, campaign_id: &str, params: &ListParams) -> Result<AdSetResponse, FacebookError>;
+        /// Fetches one ad set with the given fields.
+        fn get_ad_set(&self, ad_set_id: &str, fields: &Fields<AdSetField>) -> Result<AdSet, FacebookError>;
+        /// Creates an ad set in an ad account and returns its ID.
+        fn create_ad_set(&self, ad_account_id: &str, ad_set: AdSetCreate) -> Result<String, FacebookError>;
+        /// Applies an update to an ad set and returns its changed fields as Graph reports them.
+        fn update_ad_set(&self, ad_set_id: &str, update: AdSetUpdate) -> Result<AdSet, FacebookError>;
+        fn get_ads(&self, ad_set_id: &str, params: &ListParams) -> Result<AdResponse, FacebookError>;
+        /// Fetches one ad with the given fields.
+        fn get_ad(&self, ad_id: &str, fields: &Fields<AdField>) -> Result<Ad, FacebookError>;
+        /// Creates an ad in an ad account and returns its ID.
+        fn create_ad(&self, ad_account_id: &str, ad: AdCreate) -> Result<String, FacebookError>;
+        /// Applies an update to an ad and returns its changed fields as Graph reports them.
+        fn update_ad(&self, ad_id: &str, update: AdUpdate) -> Result<Ad, FacebookError>;
+        fn delete_ad(&self, ad_id: &str) -> Result<(), FacebookError>;
+        /// Fetches the first page of ad creatives of an ad account.
+        fn get_ad_creatives(&self, ad_account_id: &str) -> Result<AdCreativesResponse, FacebookError>;
+        /// Fetches one ad creative with the default fields.
+        fn get_creative(&self, creative_id: &str) -> Result<AdCreative, FacebookError>;
+        /// Creates an ad creative in an ad account and returns its ID.
+        fn create_ad_creative(&self, ad_account_id: &str, creative: AdCreativeCreate) -> Result<String, FacebookError>;
+        /// Uploads an image to the ad account's library and returns its hash and URL.
+        fn upload_ad_image(&self, ad_account_id: &str, source: MediaSource) -> Result<AdImage, FacebookError>;
+        /// Uploads a video from disk in chunks, finishes the upload and returns the video ID.
+        fn upload_ad_video(&self, ad_account_id: &str, path: &std::path::Path, title: Option<&str>) -> Result<String, FacebookError>;
+        /// Opens an upload session for a video file.
+        fn start_video_upload(&self, ad_account_id: &str, path: &std::path::Path) -> Result<VideoUploadSession, FacebookError>;
+        /// Sends the chunks Graph still expects; call again with the same session to resume.
+        fn continue_video_upload(&self, ad_account_id: &str, session: &mut VideoUploadSession, path: &std::path::Path) -> Result<(), FacebookError>;
+        /// Closes a fully transferred upload session and publishes the video.
+        fn finish_video_upload(&self, ad_account_id: &str, session: &VideoUploadSession, title: Option<&str>) -> Result<(), FacebookError>;
+        fn get_video_status(&self, video_id: &str) -> Result<VideoStatus, FacebookError>;
+        /// Polls a video's status until Graph reports it ready.
+        fn wait_for_video(&self, video_id: &str, poll_interval: std::time::Duration, timeout: std::time::Duration) -> Result<VideoStatus, FacebookError>;
+        /// Fetches the first page of an insights report.
+        fn get_insights(&self, object: InsightsObject<'_>, params: &InsightsParams) -> Result<InsightsResponse, FacebookError>;
+        /// Starts an async insights job and returns its report run ID.
+        fn submit_insights_job(&self, object: InsightsObject<'_>, params: &InsightsParams) -> Result<String, FacebookError>;
+        /// Reads the current state of an async insights job.
+        fn poll_job(&self, report_run_id: &str) -> Result<AdReportRun, FacebookError>;
+        /// Polls an async insights job until it completes.
+        fn wait_for_job(&self, report_run_id: &str, poll_interval: std::time::Duration, timeout: std::time::Duration) -> Result<AdReportRun, FacebookError>;
+        /// Runs an insights report as an async job and reads every row.
+        fn run_insights_job(&self, object: InsightsObject<'_>, params: &InsightsParams, poll_interval: std::time::Duration, timeout: std::time::Duration) -> Result<Vec<InsightsRow>, FacebookError>;
+        /// Fetches the first page of custom audiences of an ad account.
+        fn get_custom_audiences(&self, ad_account_id: &str) -> Result<CustomAudiencesResponse, FacebookError>;
+        /// Fetches one custom audience with the given fields.
+        fn get_custom_audience(&self, audience_id: &str, fields: &Fields<CustomAudienceField>) -> Result<CustomAudience, FacebookError>;
+        /// Creates a custom audience in an ad account and returns its ID.
+        fn create_custom_audience(&self, ad_account_id: &str, audience: CustomAudienceCreate) -> Result<String, FacebookError>;
+        /// Applies an update to a custom audience and returns its changed fields as Graph reports them.
+        fn update_custom_audience(&self, audience_id: &str, update: CustomAudienceUpdate) -> Result<CustomAudience, FacebookError>;
+        fn delete_custom_audience(&self, audience_id: &str) -> Result<(), FacebookError>;
+        /// Creates a lookalike audience in an ad account and returns its ID.
+        fn create_lookalike_audience(&self, ad_account_id: &str, lookalike: LookalikeAudienceCreate) -> Result<String, FacebookError>;
+        /// Reads Graph's estimate of the size of an audience.
+        fn get_approximate_count(&self, audience_id: &str) -> Result<ApproximateCount, FacebookError>;
+        /// Polls an audience's statuses until it is ready for delivery.
+        fn wait_for_audience(&self, audience_id: &str, poll_interval: std::time::Duration, timeout: std::time::Duration) -> Result<CustomAudience, FacebookError>;
+        /// Adds users to a customer list audience, normalized, hashed and batched.
+        fn add_audience_users(&self, audience_id: &str, users: &AudienceUsers) -> Result<AudienceUsersUpload, FacebookError>;
+        /// Removes users from a customer list audience.
+        fn remove_audience_users(&self, audience_id: &str, users: &AudienceUsers) -> Result<AudienceUsersUpload, FacebookError>;
+        /// Replaces every user of a customer list audience.
+        fn replace_audience_users(&self, audience_id: &str, users: &AudienceUsers) -> Result<AudienceUsersUpload, FacebookError>;
+        /// Searches interests by name.
+        fn search_interests(&self, query: &str, limit: Option<u32>) -> Result<Vec<InterestResult>, FacebookError>;
+        /// Suggests interests related to the given interest names.
+        fn suggest_interests(&self, interest_names: &[&str], limit: Option<u32>) -> Result<Vec<InterestResult>, FacebookError>;
+        /// Checks whether interests, given by name, can still be targeted.
+        fn validate_interests(&self, interest_names: &[&str]) -> Result<Vec<InterestValidity>, FacebookError>;
+        /// Checks whether interests, given by ID, can still be targeted.
+        fn validate_interest_ids(&self, interest_ids: &[&str]) -> Result<Vec<InterestValidity>, FacebookError>;
+        /// Searches countries, regions, cities, zips and other locations by name.
+        fn search_geolocations(&self, search: &GeoLocationSearch) -> Result<Vec<GeoLocationResult>, FacebookError>;
+        /// Lists the targeting categories of a class.
+        fn search_targeting_categories(&self, class: TargetingCategoryClass) -> Result<Vec<TargetingCategory>, FacebookError>;
+        /// Searches locales by name.
+        fn search_locales(&self, query: &str, limit: Option<u32>) -> Result<Vec<LocaleResult>, FacebookError>;
+        /// Exchanges a short-lived user token for a long-lived one.
+        fn exchange_token(&self, short_lived_token: &str) -> Result<AccessTokenResponse, FacebookError>;
+        /// Inspects a token with `/debug_token`.
+        fn debug_token(&self, input_token: &str) -> Result<DebugTokenData, FacebookError>;
+        /// Checks the client's user token for validity and imminent expiry.
+        fn check_user_token(&self, warn_within: std::time::Duration) -> Result<TokenEvent, FacebookError>;
+        /// Sends calls through the Batch API and returns one result per call, in order.
+        fn batch(&self, requests: Vec<BatchRequest>) -> Result<Vec<Result<BatchResponse, FacebookError>>, FacebookError>;
+    }
+
+    blocking_paginate! {
+        /// Iterates over every ad account associated with the user, following pagination.
+        fn ad_accounts(&self, params: &ListParams) -> AdAccount;
+        fn campaigns(&self, ad_account_id: &str, params: &ListParams) -> Campaign;
+        fn ad_sets(&self, campaign_id: &str, params: &ListParams) -> AdSet;
+        fn ads(&self, ad_set_id: &str, params: &ListParams) -> Ad;
+        fn ad_creatives(&self, ad_account_id: &str, params: &ListParams) -> AdCreative;
+        fn fetch_job_results(&self, report_run_id: &str, params: &ListParams) -> InsightsRow;
+        fn custom_audiences(&self, ad_account_id: &str, params: &ListParams) -> CustomAudience;
+    }
+}
+
+/// Blocking counterpart of [`pagination::Paginator`], usable as an `Iterator`.
+#[derive(Debug)]
+pub struct Paginator<'a, T> {
+    runtime: &'a Runtime,
+    inner: pagination::Paginator<'a, T>,
+}
+
+impl<T: DeserializeOwned> Paginator<'_, T> {
+    /// Fetches the next page, returning `None` once every page has been read.
+    pub fn next_page(&mut self) -> Option<Result<Vec<T>, FacebookError>> {
+        self.runtime.block_on(self.inner.next_page())
+    }
+
+    /// Reads every remaining page and returns all items.
+    pub fn collect_all(self) -> Result<Vec<T>, FacebookError> {
+        self.runtime.block_on(self.inner.collect_all())
+    }
+}
+
+impl<T: DeserializeOwned> Iterator for Paginator<'_, T> {
+    type Item = Result<T, FacebookError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.runtime.block_on(self.inner.next())
+    }
+}
@@ -0,0 +1,189 @@
+//! Configuration of a [`FacebookClient`] beyond the defaults of `FacebookClient::new`.
+
+use crate::client::FacebookClient;
+use crate::errors::FacebookError;
+use crate::retry::RetryPolicy;
+use crate::secret::Secret;
+use crate::token::{TokenEvent, TokenEventHandler};
+use crate::usage::UsageTracker;
+use reqwest::{Client, Proxy};
+use std::sync::Arc;
+use std::time::Duration;
+
+pub const DEFAULT_BASE_URL: &str = "https://graph.facebook.com";
+pub const DEFAULT_VIDEO_BASE_URL: &str = "https://graph-video.facebook.com";
+pub const DEFAULT_API_VERSION: &str = "v22.0";
+
+/// Builds a [`FacebookClient`].
+///
+/// When a custom `reqwest::Client` is supplied with [`http_client`](Self::http_client),
+/// the timeout, user agent and proxy settings of the builder are ignored.
+#[derive(Debug)]
+pub struct FacebookClientBuilder {
+    app_id: String,
+    app_secret: Secret,
+    user_token: Secret,
+    version: String,
+    base_url: String,
+    video_base_url: String,
+    timeout: Option<Duration>,
+    connect_timeout: Option<Duration>,
+    user_agent: Option<String>,
+    proxy: Option<String>,
+    http_client: Option<Client>,
+    fetch_app_access_token: bool,
+    retry_policy: RetryPolicy,
+    pause_at_usage_pct: Option<f64>,
+    use_appsecret_proof: bool,
+    token_event_handler: Option<TokenEventHandler>,
+}
+
+impl FacebookClientBuilder {
+    pub fn new(app_id: &str, app_secret: &str, user_token: &str) -> Self {
+        FacebookClientBuilder {
+            app_id: app_id.to_string(),
+            app_secret: Secret::new(app_secret),
+            user_token: Secret::new(user_token),
+            version: DEFAULT_API_VERSION.to_string(),
+            base_url: DEFAULT_BASE_URL.to_string(),
+            video_base_url: DEFAULT_VIDEO_BASE_URL.to_string(),
+            timeout: None,
+            connect_timeout: None,
+            user_agent: None,
+            proxy: None,
+            http_client: None,
+            fetch_app_access_token: true,
+            retry_policy: RetryPolicy::default(),
+            pause_at_usage_pct: None,
+            use_appsecret_proof: false,
+            token_event_handler: None,
+        }
+    }
+
+    /// Graph API version, such as `v22.0`.
+    pub fn version(mut self, version: &str) -> Self {
+        self.version = version.to_string();
+        self
+    }
+
+    /// Scheme and host Graph calls are sent to, e.g. a local stub or an egress proxy.
+    pub fn base_url(mut self, base_url: &str) -> Self {
+        self.base_url = base_url.trim_end_matches('/').to_string();
+        self
+    }
+
+    /// Scheme and host video uploads are sent to.
+    pub fn video_base_url(mut self, video_base_url: &str) -> Self {
+        self.video_base_url = video_base_url.trim_end_matches('/').to_string();
+        self
+    }
+
+    /// Total time allowed for each HTTP request.
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    pub fn connect_timeout(mut self, connect_timeout: Duration) -> Self {
+        self.connect_timeout = Some(connect_timeout);
+        self
+    }
+
+    pub fn user_agent(mut self, user_agent: &str) -> Self {
+        self.user_agent = Some(user_agent.to_string());
+        self
+    }
+
+    /// Routes every request through the proxy at `proxy_url`.
+    pub fn proxy(mut self, proxy_url: &str) -> Self {
+        self.proxy = Some(proxy_url.to_string());
+        self
+    }
+
+    /// Uses a preconfigured `reqwest::Client` instead of building one.
+    pub fn http_client(mut self, http_client: Client) -> Self {
+        self.http_client = Some(http_client);
+        self
+    }
+
+    /// Whether `build` fetches the App Access Token. Defaults to `true`.
+    pub fn fetch_app_access_token(mut self, fetch: bool) -> Self {
+        self.fetch_app_access_token = fetch;
+        self
+    }
+
+    pub fn retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
+        self
+    }
+
+    /// See [`FacebookClient::with_usage_pause`].
+    pub fn usage_pause(mut self, threshold_pct: f64) -> Self {
+        self.pause_at_usage_pct = Some(threshold_pct);
+        self
+    }
+
+    /// See [`FacebookClient::with_appsecret_proof`].
+    pub fn appsecret_proof(mut self, enabled: bool) -> Self {
+        self.use_appsecret_proof = enabled;
+        self
+    }
+
+    /// See [`FacebookClient::with_token_event_handler`].
+    pub fn token_event_handler(mut self, handler: impl Fn(&TokenEvent) + Send + Sync + 'static) -> Self {
+        self.token_event_handler = Some(TokenEventHandler::new(handler));
+        self
+    }
+
+    /// Builds the client, fetching the App Access Token unless disabled.
+    pub async fn build(self) -> Result<FacebookClient, FacebookError> {
+        let fetch_app_access_token = self.fetch_app_access_token;
+        let mut client = self.build_without_token()?;
+        if fetch_app_access_token {
+            client.refresh_app_access_token().await?;
+        }
+        Ok(client)
+    }
+
+    fn build_without_token(self) -> Result<FacebookClient, FacebookError> {
+        let http_client = match self.http_client {
+            Some(http_client) => http_client,
+            None => {
+                let mut builder = Client::builder();
+                if let Some(timeout) = self.timeout {
+                    builder = builder.timeout(timeout);
+                }
+                if let Some(connect_timeout) = self.connect_timeout {
+                    builder = builder.connect_timeout(connect_timeout);
+                }
+                if let Some(user_agent) = &self.user_agent {
+                    builder = builder.user_agent(user_agent);
+                }
+                if let Some(proxy) = &self.proxy {
+                    let proxy = Proxy::all(proxy)
+                        .map_err(|e| FacebookError::Unexpected(format!("Invalid proxy URL: {}", e)))?;
+                    builder = builder.proxy(proxy);
+                }
+                builder
+                    .build()
+                    .map_err(|e| FacebookError::Unexpected(format!("Failed to build HTTP client: {}", e)))?
+            }
+        };
+
+        Ok(FacebookClient {
+            app_id: self.app_id,
+            app_secret: self.app_secret,
+            user_token: self.user_token,
+            app_access_token: None,
+            http_client,
+            version: self.version,
+            base_url: self.base_url,
+            video_base_url: self.video_base_url,
+            retry_policy: self.retry_policy,
+            usage: Arc::new(UsageTracker::default()),
+            pause_at_usage_pct: self.pause_at_usage_pct,
+            use_appsecret_proof: self.use_appsecret_proof,
+            token_event_handler: self.token_event_handler,
+        })
+    }
+}
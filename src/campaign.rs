@@ -0,0 +1,371 @@
+//! Creating and updating campaigns.
+
+use crate::client::{to_json, FacebookClient, GraphRequest};
+use crate::errors::FacebookError;
+use crate::fields::{CampaignField, Fields};
+use crate::types::{Campaign, CreatedObject};
+
+graph_enum! {
+    /// Outcome-driven (ODAX) campaign objectives.
+    pub enum Objective {
+        AppPromotion => "OUTCOME_APP_PROMOTION",
+        Awareness => "OUTCOME_AWARENESS",
+        Engagement => "OUTCOME_ENGAGEMENT",
+        Leads => "OUTCOME_LEADS",
+        Sales => "OUTCOME_SALES",
+        Traffic => "OUTCOME_TRAFFIC",
+        _ => Other(String),
+    }
+}
+
+graph_enum! {
+    /// Status an advertiser can set on a campaign, ad set or ad.
+    pub enum ConfiguredStatus {
+        Active => "ACTIVE",
+        Paused => "PAUSED",
+        Deleted => "DELETED",
+        Archived => "ARCHIVED",
+        _ => Other(String),
+    }
+}
+
+graph_enum! {
+    pub enum SpecialAdCategory {
+        None => "NONE",
+        Employment => "EMPLOYMENT",
+        Housing => "HOUSING",
+        Credit => "CREDIT",
+        FinancialProductsServices => "FINANCIAL_PRODUCTS_SERVICES",
+        IssuesElectionsPolitics => "ISSUES_ELECTIONS_POLITICS",
+        OnlineGamblingAndGaming => "ONLINE_GAMBLING_AND_GAMING",
+    }
+}
+
+graph_enum! {
+    pub enum BuyingType {
+        Auction => "AUCTION",
+        Reserved => "RESERVED",
+    }
+}
+
+graph_enum! {
+    pub enum BidStrategy {
+        LowestCostWithoutCap => "LOWEST_COST_WITHOUT_CAP",
+        LowestCostWithBidCap => "LOWEST_COST_WITH_BID_CAP",
+        CostCap => "COST_CAP",
+        LowestCostWithMinRoas => "LOWEST_COST_WITH_MIN_ROAS",
+        _ => Other(String),
+    }
+}
+
+/// Parameters of a new campaign, named after the fields of [`crate::types::Campaign`].
+///
+/// Budgets and the spend cap are in the minor unit of the ad account currency
+/// (cents for USD). Times are ISO 8601 strings. Setting a daily or lifetime budget
+/// turns on campaign budget optimization (CBO); without one, budgets are set on
+/// the ad sets.
+#[derive(Debug, Clone)]
+pub struct CampaignCreate {
+    pub name: String,
+    pub objective: Objective,
+    pub status: ConfiguredStatus,
+    pub special_ad_categories: Vec<SpecialAdCategory>,
+    pub special_ad_category_country: Vec<String>,
+    pub buying_type: Option<BuyingType>,
+    pub bid_strategy: Option<BidStrategy>,
+    pub daily_budget: Option<u64>,
+    pub lifetime_budget: Option<u64>,
+    pub spend_cap: Option<u64>,
+    pub start_time: Option<String>,
+    pub stop_time: Option<String>,
+}
+
+impl CampaignCreate {
+    /// A paused campaign with no special ad category.
+    pub fn new(name: &str, objective: Objective) -> Self {
+        CampaignCreate {
+            name: name.to_string(),
+            objective,
+            status: ConfiguredStatus::Paused,
+            special_ad_categories: Vec::new(),
+            special_ad_category_country: Vec::new(),
+            buying_type: None,
+            bid_strategy: None,
+            daily_budget: None,
+            lifetime_budget: None,
+            spend_cap: None,
+            start_time: None,
+            stop_time: None,
+        }
+    }
+
+    pub fn status(mut self, status: ConfiguredStatus) -> Self {
+        self.status = status;
+        self
+    }
+
+    pub fn special_ad_categories(mut self, categories: &[SpecialAdCategory]) -> Self {
+        self.special_ad_categories = categories.to_vec();
+        self
+    }
+
+    /// ISO 3166 country codes the special ad categories apply to.
+    pub fn special_ad_category_country(mut self, countries: &[&str]) -> Self {
+        self.special_ad_category_country = countries.iter().map(|country| country.to_string()).collect();
+        self
+    }
+
+    pub fn buying_type(mut self, buying_type: BuyingType) -> Self {
+        self.buying_type = Some(buying_type);
+        self
+    }
+
+    pub fn bid_strategy(mut self, bid_strategy: BidStrategy) -> Self {
+        self.bid_strategy = Some(bid_strategy);
+        self
+    }
+
+    pub fn daily_budget(mut self, amount: u64) -> Self {
+        self.daily_budget = Some(amount);
+        self
+    }
+
+    pub fn lifetime_budget(mut self, amount: u64) -> Self {
+        self.lifetime_budget = Some(amount);
+        self
+    }
+
+    pub fn spend_cap(mut self, amount: u64) -> Self {
+        self.spend_cap = Some(amount);
+        self
+    }
+
+    pub fn start_time(mut self, start_time: &str) -> Self {
+        self.start_time = Some(start_time.to_string());
+        self
+    }
+
+    pub fn stop_time(mut self, stop_time: &str) -> Self {
+        self.stop_time = Some(stop_time.to_string());
+        self
+    }
+
+    /// Checks that the name is set, that only one kind of budget is given and is
+    /// above zero, that a lifetime budget has a `stop_time` and a `spend_cap` not
+    /// below it, that a bid strategy comes with a campaign budget (and RESERVED
+    /// buying without one), that special ad category countries come with a
+    /// category, and that the status is ACTIVE or PAUSED.
+    pub fn validate(&self) -> Result<(), FacebookError> {
+        if self.name.trim().is_empty() {
+            return Err(FacebookError::invalid("name must not be empty"));
+        }
+        if self.daily_budget.is_some() && self.lifetime_budget.is_some() {
+            return Err(FacebookError::invalid("set either daily_budget or lifetime_budget, not both"));
+        }
+        if self.daily_budget == Some(0) || self.lifetime_budget == Some(0) || self.spend_cap == Some(0) {
+            return Err(FacebookError::invalid("budgets and spend_cap must be greater than zero"));
+        }
+        if self.lifetime_budget.is_some() && self.stop_time.is_none() {
+            return Err(FacebookError::invalid("a lifetime_budget requires a stop_time"));
+        }
+        let has_campaign_budget = self.daily_budget.is_some() || self.lifetime_budget.is_some();
+        if self.bid_strategy.is_some() && !has_campaign_budget {
+            return Err(FacebookError::invalid(
+                "bid_strategy can only be set on a campaign with a campaign budget (CBO); set it on the ad sets instead",
+            ));
+        }
+        if let (Some(spend_cap), Some(lifetime_budget)) = (self.spend_cap, self.lifetime_budget) {
+            if spend_cap < lifetime_budget {
+                return Err(FacebookError::invalid("spend_cap must not be lower than lifetime_budget"));
+            }
+        }
+        if self.buying_type == Some(BuyingType::Reserved) && has_campaign_budget {
+            return Err(FacebookError::invalid("RESERVED campaigns do not take a campaign budget"));
+        }
+        let has_special_category = self
+            .special_ad_categories
+            .iter()
+            .any(|category| *category != SpecialAdCategory::None);
+        if !self.special_ad_category_country.is_empty() && !has_special_category {
+            return Err(FacebookError::invalid("special_ad_category_country requires a special ad category"));
+        }
+        if matches!(self.status, ConfiguredStatus::Deleted | ConfiguredStatus::Archived) {
+            return Err(FacebookError::invalid("a new campaign must be ACTIVE or PAUSED"));
+        }
+        Ok(())
+    }
+
+    fn to_form(&self) -> Vec<(String, String)> {
+        let mut form = vec![
+            ("name".to_string(), self.name.clone()),
+            ("objective".to_string(), self.objective.to_string()),
+            ("status".to_string(), self.status.to_string()),
+            ("special_ad_categories".to_string(), to_json(&self.special_ad_categories)),
+        ];
+        if !self.special_ad_category_country.is_empty() {
+            form.push((
+                "special_ad_category_country".to_string(),
+                to_json(&self.special_ad_category_country),
+            ));
+        }
+        let optional = [
+            ("buying_type", self.buying_type.map(|value| value.to_string())),
+            ("bid_strategy", self.bid_strategy.as_ref().map(|value| value.to_string())),
+            ("daily_budget", self.daily_budget.map(|value| value.to_string())),
+            ("lifetime_budget", self.lifetime_budget.map(|value| value.to_string())),
+            ("spend_cap", self.spend_cap.map(|value| value.to_string())),
+            ("start_time", self.start_time.clone()),
+            ("stop_time", self.stop_time.clone()),
+        ];
+        form.extend(
+            optional
+                .into_iter()
+                .filter_map(|(key, value)| value.map(|value| (key.to_string(), value))),
+        );
+        form
+    }
+}
+
+/// Changes to an existing campaign. Only the fields that are set are sent.
+#[derive(Debug, Clone, Default)]
+pub struct CampaignUpdate {
+    pub name: Option<String>,
+    pub status: Option<ConfiguredStatus>,
+    pub daily_budget: Option<u64>,
+    pub lifetime_budget: Option<u64>,
+    pub spend_cap: Option<u64>,
+    pub bid_strategy: Option<BidStrategy>,
+    pub stop_time: Option<String>,
+}
+
+impl CampaignUpdate {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn name(mut self, name: &str) -> Self {
+        self.name = Some(name.to_string());
+        self
+    }
+
+    /// `PAUSED` pauses, `ARCHIVED` archives and `DELETED` deletes the campaign.
+    pub fn status(mut self, status: ConfiguredStatus) -> Self {
+        self.status = Some(status);
+        self
+    }
+
+    pub fn daily_budget(mut self, amount: u64) -> Self {
+        self.daily_budget = Some(amount);
+        self
+    }
+
+    pub fn lifetime_budget(mut self, amount: u64) -> Self {
+        self.lifetime_budget = Some(amount);
+        self
+    }
+
+    pub fn spend_cap(mut self, amount: u64) -> Self {
+        self.spend_cap = Some(amount);
+        self
+    }
+
+    pub fn bid_strategy(mut self, bid_strategy: BidStrategy) -> Self {
+        self.bid_strategy = Some(bid_strategy);
+        self
+    }
+
+    pub fn stop_time(mut self, stop_time: &str) -> Self {
+        self.stop_time = Some(stop_time.to_string());
+        self
+    }
+
+    /// Checks that the update changes something, that a new name is not empty,
+    /// and that at most one kind of budget is set, above zero.
+    pub fn validate(&self) -> Result<(), FacebookError> {
+        if self.changed_fields().is_empty() {
+            return Err(FacebookError::invalid("the update does not change any field"));
+        }
+        if self.name.as_deref().is_some_and(|name| name.trim().is_empty()) {
+            return Err(FacebookError::invalid("name must not be empty"));
+        }
+        if self.daily_budget.is_some() && self.lifetime_budget.is_some() {
+            return Err(FacebookError::invalid("set either daily_budget or lifetime_budget, not both"));
+        }
+        if self.daily_budget == Some(0) || self.lifetime_budget == Some(0) || self.spend_cap == Some(0) {
+            return Err(FacebookError::invalid("budgets and spend_cap must be greater than zero"));
+        }
+        Ok(())
+    }
+
+    /// The campaign fields this update writes.
+    pub fn changed_fields(&self) -> Fields<CampaignField> {
+        let changes = [
+            (CampaignField::Name, self.name.is_some()),
+            (CampaignField::Status, self.status.is_some()),
+            (CampaignField::DailyBudget, self.daily_budget.is_some()),
+            (CampaignField::LifetimeBudget, self.lifetime_budget.is_some()),
+            (CampaignField::SpendCap, self.spend_cap.is_some()),
+            (CampaignField::BidStrategy, self.bid_strategy.is_some()),
+            (CampaignField::StopTime, self.stop_time.is_some()),
+        ];
+        changes
+            .into_iter()
+            .filter(|(_, changed)| *changed)
+            .fold(Fields::new(), |fields, (field, _)| fields.field(field))
+    }
+
+    fn to_form(&self) -> Vec<(String, String)> {
+        let values = [
+            ("name", self.name.clone()),
+            ("status", self.status.as_ref().map(|value| value.to_string())),
+            ("daily_budget", self.daily_budget.map(|value| value.to_string())),
+            ("lifetime_budget", self.lifetime_budget.map(|value| value.to_string())),
+            ("spend_cap", self.spend_cap.map(|value| value.to_string())),
+            ("bid_strategy", self.bid_strategy.as_ref().map(|value| value.to_string())),
+            ("stop_time", self.stop_time.clone()),
+        ];
+        values
+            .into_iter()
+            .filter_map(|(key, value)| value.map(|value| (key.to_string(), value)))
+            .collect()
+    }
+}
+
+impl FacebookClient {
+    /// Creates a campaign in an ad account and returns its ID.
+    ///
+    /// The request is validated locally first; see [`CampaignCreate::validate`].
+    pub async fn create_campaign(&self, ad_account_id: &str, campaign: CampaignCreate) -> Result<String, FacebookError> {
+        let created: CreatedObject = self.execute(Self::create_campaign_request(ad_account_id, &campaign)?).await?;
+        Ok(created.id)
+    }
+
+    pub(crate) fn create_campaign_request(
+        ad_account_id: &str,
+        campaign: &CampaignCreate,
+    ) -> Result<GraphRequest, FacebookError> {
+        campaign.validate()?;
+        let mut request = GraphRequest::post(format!("act_{}/campaigns", ad_account_id));
+        for (key, value) in campaign.to_form() {
+            request = request.form(&key, value);
+        }
+        Ok(request)
+    }
+
+    /// Applies an update to a campaign and returns the campaign as Graph reports it
+    /// afterwards, limited to `id` and the fields that were changed.
+    pub async fn update_campaign(&self, campaign_id: &str, update: CampaignUpdate) -> Result<Campaign, FacebookError> {
+        self.execute_success(Self::update_campaign_request(campaign_id, &update)?).await?;
+        let fields = update.changed_fields().field(CampaignField::Id);
+        self.get_campaign(campaign_id, &fields).await
+    }
+
+    pub(crate) fn update_campaign_request(campaign_id: &str, update: &CampaignUpdate) -> Result<GraphRequest, FacebookError> {
+        update.validate()?;
+        let mut request = GraphRequest::post(campaign_id).idempotent(true);
+        for (key, value) in update.to_form() {
+            request = request.form(&key, value);
+        }
+        Ok(request)
+    }
+}
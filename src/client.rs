@@ -1,15 +1,177 @@
-use crate::errors::FacebookError;
+use crate::builder::FacebookClientBuilder;
+use crate::errors::{FacebookError, GraphApiError};
+use crate::fields::{AdField, AdSetField, CampaignField, Field, Fields};
+use crate::media::{multipart_form, FilePart};
+use crate::pagination::{ListParams, Paginator};
+use crate::retry::{RetryPolicy, Retryability};
+use crate::secret::{scrub_secrets, Secret};
+use crate::token::{TokenEvent, TokenEventHandler};
 use crate::types::*;
-use reqwest::blocking::*; 
+use crate::usage::{ad_account_id_from_path, UsageSnapshot, UsageTracker};
+use hmac::{Hmac, Mac};
+use reqwest::{Client, Method, StatusCode, Url};
+use serde::de::DeserializeOwned;
+use sha2::Sha256;
+use std::sync::Arc;
+use std::time::Duration;
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct FacebookClient {
     pub app_id: String,
-    pub app_secret: String,
-    pub user_token: String,
-    pub app_access_token: Option<String>,
+    pub app_secret: Secret,
+    pub user_token: Secret,
+    pub app_access_token: Option<Secret>,
     pub http_client: Client,
     pub version: String,  
+    pub base_url: String,
+    pub video_base_url: String,
+    pub retry_policy: RetryPolicy,
+    pub usage: Arc<UsageTracker>,
+    /// Pause before a request once reported usage reaches this percentage.
+    pub pause_at_usage_pct: Option<f64>,
+    /// Attach `appsecret_proof` to every request that carries an access token.
+    pub use_appsecret_proof: bool,
+    pub token_event_handler: Option<TokenEventHandler>,
+}
+
+/// Which access token, if any, the executor attaches to a request.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum TokenKind {
+    User,
+    /// The App Access Token, or `app_id|app_secret` if none has been fetched.
+    App,
+    None,
+}
+
+/// A single Graph API call, described independently of how it is sent.
+#[derive(Debug, Clone)]
+pub(crate) struct GraphRequest {
+    pub method: Method,
+    pub path: String,
+    pub query: Vec<(String, String)>,
+    pub form: Vec<(String, String)>,
+    /// Files to upload. When present, `form` is sent as multipart text parts alongside them.
+    pub files: Vec<FilePart>,
+    pub token: TokenKind,
+    /// Whether sending the request twice has the same effect as sending it once.
+    pub idempotent: bool,
+}
+
+/// A failed attempt, together with whether it may be retried.
+struct Failure {
+    error: FacebookError,
+    retryability: Retryability,
+    /// How long Graph asked callers to wait, from the usage headers of the failed response.
+    retry_after: Option<Duration>,
+}
+
+impl Failure {
+    fn new(error: FacebookError, retryability: Retryability) -> Self {
+        Failure {
+            error,
+            retryability,
+            retry_after: None,
+        }
+    }
+}
+
+/// Computes the `appsecret_proof` of an access token: the hex-encoded
+/// HMAC-SHA256 of the token, keyed with the app secret.
+///
+/// See <https://developers.facebook.com/docs/graph-api/securing-requests#appsecret_proof>.
+pub fn appsecret_proof(app_secret: &str, access_token: &str) -> String {
+    let mut mac = Hmac::<Sha256>::new_from_slice(app_secret.as_bytes())
+        .expect("HMAC accepts keys of any length");
+    mac.update(access_token.as_bytes());
+    hex::encode(mac.finalize().into_bytes())
+}
+
+impl GraphRequest {
+    pub fn new(method: Method, path: impl Into<String>) -> Self {
+        GraphRequest {
+            idempotent: method == Method::GET,
+            method,
+            path: path.into(),
+            query: Vec::new(),
+            form: Vec::new(),
+            files: Vec::new(),
+            token: TokenKind::User,
+        }
+    }
+
+    pub fn get(path: impl Into<String>) -> Self {
+        Self::new(Method::GET, path)
+    }
+
+    pub fn post(path: impl Into<String>) -> Self {
+        Self::new(Method::POST, path)
+    }
+
+    pub fn delete(path: impl Into<String>) -> Self {
+        Self::new(Method::DELETE, path)
+    }
+
+    /// Rebuilds a GET request from an absolute URL returned by Graph, such as `paging.next`.
+    ///
+    /// The embedded `access_token` is dropped; the executor attaches the client's own token.
+    pub fn from_url(url: &str) -> Result<Self, FacebookError> {
+        let parsed = Url::parse(url)
+            .map_err(|e| FacebookError::Unexpected(format!("Invalid paging URL: {}", e)))?;
+        let mut base = parsed.clone();
+        base.set_query(None);
+        base.set_fragment(None);
+        let mut request = Self::get(base.as_str());
+        for (key, value) in parsed.query_pairs() {
+            if key != "access_token" {
+                request = request.query(&key, value.into_owned());
+            }
+        }
+        Ok(request)
+    }
+
+    pub fn query(mut self, key: &str, value: impl Into<String>) -> Self {
+        self.query.push((key.to_string(), value.into()));
+        self
+    }
+
+    pub fn form(mut self, key: &str, value: impl Into<String>) -> Self {
+        self.form.push((key.to_string(), value.into()));
+        self
+    }
+
+    pub fn file(mut self, file: FilePart) -> Self {
+        self.files.push(file);
+        self
+    }
+
+    pub fn fields<F: Field>(self, fields: &Fields<F>) -> Self {
+        self.set_query("fields", fields.to_string())
+    }
+
+    /// Sets a query parameter, replacing any earlier value.
+    pub fn set_query(mut self, key: &str, value: impl Into<String>) -> Self {
+        self.query.retain(|(existing, _)| existing != key);
+        self.query(key, value)
+    }
+
+    pub fn token(mut self, token: TokenKind) -> Self {
+        self.token = token;
+        self
+    }
+
+    /// Marks whether repeating the request leaves Graph in the same state, which
+    /// lets the retry policy resend it after a failure that may have been applied.
+    /// GETs are idempotent by default; updates that set every field to an absolute
+    /// value can opt in, while creates and deletes cannot.
+    pub fn idempotent(mut self, idempotent: bool) -> Self {
+        self.idempotent = idempotent;
+        self
+    }
+}
+
+/// Encodes a structured parameter as the JSON string Graph expects in a form or query value.
+pub(crate) fn to_json<T: serde::Serialize>(value: &T) -> String {
+    serde_json::to_string(value).expect("request parameters serialize to JSON")
 }
 
 impl FacebookClient {
@@ -25,279 +187,331 @@ impl FacebookClient {
     /// # Returns
     ///
     /// A `Result` containing a new instance of `FacebookClient` or a `FacebookError`.
-    pub fn new(app_id: &str, app_secret: &str, user_token: &str, version: &str) -> Result<Self, FacebookError> {
-        let mut client = FacebookClient {
-            app_id: app_id.to_string(),
-            app_secret: app_secret.to_string(),
-            user_token: user_token.to_string(),
-            app_access_token: None,
-            http_client: Client::new(),
-            version: version.to_string(), 
-        };
-        client.refresh_app_access_token()?;
-        Ok(client)
+    pub async fn new(app_id: &str, app_secret: &str, user_token: &str, version: &str) -> Result<Self, FacebookError> {
+        Self::builder(app_id, app_secret, user_token)
+            .version(version)
+            .build()
+            .await
     }
 
-    /// Fetches or refreshes the App Access Token for the Facebook application.
+    /// Starts configuring a client: base URL, timeouts, proxy, HTTP client and more.
+    pub fn builder(app_id: &str, app_secret: &str, user_token: &str) -> FacebookClientBuilder {
+        FacebookClientBuilder::new(app_id, app_secret, user_token)
+    }
+
+    /// Replaces the retry policy used for every request.
+    pub fn with_retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
+        self
+    }
+
+    /// Pauses requests once Graph reports usage at or above `threshold_pct` percent,
+    /// for as long as Graph estimates it needs to recover.
+    pub fn with_usage_pause(mut self, threshold_pct: f64) -> Self {
+        self.pause_at_usage_pct = Some(threshold_pct);
+        self
+    }
+
+    /// Signs every request with `appsecret_proof`, as required by apps that
+    /// enable "Require App Secret" in their advanced settings.
+    pub fn with_appsecret_proof(mut self, enabled: bool) -> Self {
+        self.use_appsecret_proof = enabled;
+        self
+    }
+
+    /// Latest rate-limit usage reported by Graph.
+    pub fn usage(&self) -> &UsageTracker {
+        &self.usage
+    }
+
+    /// Builds the absolute URL of a Graph API path for the configured version.
     ///
-    /// # Returns
+    /// Paths that are already absolute URLs are used as they are.
+    fn graph_url(&self, path: &str) -> String {
+        if path.starts_with("https://") || path.starts_with("http://") {
+            return path.to_string();
+        }
+        format!("{}/{}/{}", self.base_url, self.version, path.trim_start_matches('/'))
+    }
+
+    /// Sends a request and decodes the JSON response body into `T`.
     ///
-    /// A `Result` indicating success or `FacebookError` if there's an issue.
-    pub fn refresh_app_access_token(&mut self) -> Result<(), FacebookError> {
-        let url = format!(
-            "https://graph.facebook.com/{}/oauth/access_token?client_id={}&client_secret={}&grant_type=client_credentials",
-            self.version, self.app_id, self.app_secret
-        );
+    /// Errors are classified the same way for every endpoint:
+    ///
+    /// * transport failures become `HttpError`,
+    /// * a non-success status with a Graph `{"error": {...}}` body becomes `GraphApiError`,
+    /// * otherwise a `401` status becomes `Unauthorized`,
+    /// * any other non-success status becomes `HttpError` with the status and body,
+    /// * an unreadable or undecodable body becomes `Unexpected`.
+    ///
+    /// Failed attempts are retried according to the client's [`RetryPolicy`].
+    pub(crate) async fn execute<T: DeserializeOwned>(&self, request: GraphRequest) -> Result<T, FacebookError> {
+        let mut attempt = 1;
+        loop {
+            match self.execute_once(&request).await {
+                Ok(value) => return Ok(value),
+                Err(failure) => {
+                    if !self
+                        .retry_policy
+                        .should_retry(failure.retryability, request.idempotent, attempt)
+                    {
+                        if let Some(event) = failure.error.graph_error().and_then(TokenEvent::from_graph_error) {
+                            self.notify_token_event(&event);
+                        }
+                        return Err(failure.error);
+                    }
+                    let mut delay = self.retry_policy.backoff(attempt);
+                    if let Some(retry_after) = failure.retry_after {
+                        delay = delay.max(retry_after.min(self.retry_policy.max_delay));
+                    }
+                    tokio::time::sleep(delay).await;
+                    attempt += 1;
+                }
+            }
+        }
+    }
+
+    async fn execute_once<T: DeserializeOwned>(&self, request: &GraphRequest) -> Result<T, Failure> {
+        let ad_account_id = ad_account_id_from_path(&request.path);
+        if let Some(threshold_pct) = self.pause_at_usage_pct {
+            if let Some(wait) = self.usage.pause_before(ad_account_id, threshold_pct) {
+                tokio::time::sleep(wait).await;
+            }
+        }
+
+        // The token travels in the Authorization header so it never appears in a URL.
+        let access_token = match request.token {
+            TokenKind::User => Some(self.user_token.clone()),
+            TokenKind::App => Some(self.app_access_token.clone().unwrap_or_else(|| {
+                Secret::new(format!("{}|{}", self.app_id, self.app_secret.expose()))
+            })),
+            TokenKind::None => None,
+        };
+
+        let mut query = request.query.clone();
+        if let (Some(token), true) = (&access_token, self.use_appsecret_proof) {
+            query.push((
+                "appsecret_proof".to_string(),
+                appsecret_proof(self.app_secret.expose(), token.expose()),
+            ));
+        }
 
-        let response = self
+        let mut builder = self
             .http_client
-            .get(&url)
-            .send() 
-            .map_err(|e| FacebookError::HttpError(e.to_string()))?;
+            .request(request.method.clone(), self.graph_url(&request.path))
+            .query(&query);
+        if let Some(token) = &access_token {
+            builder = builder.bearer_auth(token.expose());
+        }
+        if !request.files.is_empty() {
+            let form = multipart_form(&request.form, &request.files)
+                .await
+                .map_err(|error| Failure::new(error, Retryability::Never))?;
+            builder = builder.multipart(form);
+        } else if !request.form.is_empty() {
+            builder = builder.form(&request.form);
+        }
+
+        let response = builder.send().await.map_err(|e| {
+            // A refused connection never reached Graph; anything later might have.
+            let retryability = if e.is_connect() {
+                Retryability::Always
+            } else if e.is_timeout() || e.is_request() {
+                Retryability::IfIdempotent
+            } else {
+                Retryability::Never
+            };
+            Failure::new(FacebookError::HttpError(scrub_secrets(&e.without_url().to_string())), retryability)
+        })?;
 
         let status = response.status();
-        let response_text = response
-            .text()
-            .map_err(|e| FacebookError::Unexpected(format!("Failed to read response text: {}", e)))?;
-
-        if status.is_success() {
-            let token_response: AppAccessResponse = serde_json::from_str(&response_text)
-                .map_err(|e| FacebookError::Unexpected(format!("Failed to parse JSON: {}", e)))?;
-            self.app_access_token = Some(token_response.access_token);
+        let snapshot = UsageSnapshot::from_headers(response.headers());
+        let retry_after = snapshot.as_ref().and_then(UsageSnapshot::regain_access_after);
+        if let Some(snapshot) = snapshot {
+            self.usage.record(ad_account_id, snapshot);
+        }
+
+        let response_text = response.text().await.map_err(|e| {
+            Failure::new(
+                FacebookError::Unexpected(scrub_secrets(&format!("Failed to read response text: {}", e.without_url()))),
+                Retryability::IfIdempotent,
+            )
+        })?;
+
+        if !status.is_success() {
+            let response_text = scrub_secrets(&response_text);
+            if let Some(error) = GraphApiError::from_response(status.as_u16(), &response_text) {
+                let retryability = self.classify_graph_error(&error);
+                let mut failure = Failure::new(FacebookError::GraphApiError(Box::new(error)), retryability);
+                failure.retry_after = retry_after;
+                return Err(failure);
+            }
+            if status == StatusCode::UNAUTHORIZED {
+                return Err(Failure::new(FacebookError::Unauthorized, Retryability::Never));
+            }
+            let retryability = if status == StatusCode::TOO_MANY_REQUESTS {
+                Retryability::Always
+            } else if status.is_server_error() {
+                Retryability::IfIdempotent
+            } else {
+                Retryability::Never
+            };
+            return Err(Failure::new(
+                FacebookError::HttpError(format!(
+                    "Unexpected response status: {}. Response: {}",
+                    status, response_text
+                )),
+                retryability,
+            ));
+        }
+
+        serde_json::from_str(&response_text).map_err(|e| {
+            Failure::new(
+                FacebookError::Unexpected(format!("Failed to parse JSON: {}", e)),
+                Retryability::Never,
+            )
+        })
+    }
+
+    fn classify_graph_error(&self, error: &GraphApiError) -> Retryability {
+        if error.code.is_some_and(|code| self.retry_policy.is_retryable_code(code)) {
+            Retryability::Always
+        } else if error.is_transient == Some(true) || error.status >= 500 {
+            Retryability::IfIdempotent
+        } else {
+            Retryability::Never
+        }
+    }
+
+    /// Sends a mutating request whose response is Graph's `{"success": bool}` body.
+    pub(crate) async fn execute_success(&self, request: GraphRequest) -> Result<(), FacebookError> {
+        let response: SuccessResponse = self.execute(request).await?;
+        if response.success {
             Ok(())
-        } else if status == reqwest::StatusCode::UNAUTHORIZED {
-            Err(FacebookError::Unauthorized)
         } else {
-            Err(FacebookError::HttpError(format!(
-                "Unexpected response status: {}. Response: {}",
-                status, response_text
-            )))
+            Err(FacebookError::Unexpected("Graph API reported success: false".to_string()))
         }
     }
 
-    /// Fetches ad accounts associated with the user.
+    /// Fetches or refreshes the App Access Token for the Facebook application.
     ///
     /// # Returns
     ///
-    /// A `Result` containing a list of ad accounts or a `FacebookError`.
-    pub fn get_ad_accounts(&self) -> Result<AdsAccountsResponse, FacebookError> {
-        let url = format!(
-            "https://graph.facebook.com/{}/me/adaccounts?access_token={}",
-            self.version, self.user_token
-        );
-
-        let response = self
-            .http_client
-            .get(&url)
-            .send() 
-            .map_err(|e| FacebookError::HttpError(e.to_string()))?; 
-
-        let status = response.status();
-        let response_text = response
-            .text()
-            .map_err(|e| FacebookError::Unexpected(format!("Failed to read response text: {}", e)))?;
+    /// A `Result` indicating success or `FacebookError` if there's an issue.
+    pub async fn refresh_app_access_token(&mut self) -> Result<(), FacebookError> {
+        // The secret goes in the POST body rather than the query string.
+        let request = GraphRequest::post("oauth/access_token")
+            .token(TokenKind::None)
+            .idempotent(true)
+            .form("client_id", self.app_id.as_str())
+            .form("client_secret", self.app_secret.expose())
+            .form("grant_type", "client_credentials");
 
-        if status.is_client_error() || status.is_server_error() {
-            return Err(FacebookError::Unexpected(response_text));
-        }
+        let token_response: AppAccessResponse = self.execute(request).await?;
+        self.app_access_token = Some(token_response.access_token);
+        Ok(())
+    }
 
-        let ad_accounts_response: AdsAccountsResponse = serde_json::from_str(&response_text)
-            .map_err(|e| FacebookError::Unexpected(format!("Failed to parse JSON: {}", e)))?;
+    /// Fetches ad accounts associated with the user.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing a list of ad accounts or a `FacebookError`.
+    pub async fn get_ad_accounts(&self) -> Result<AdsAccountsResponse, FacebookError> {
+        self.execute(Self::ad_accounts_request()).await
+    }
 
-        Ok(ad_accounts_response)
+    /// Iterates over every ad account associated with the user, following pagination.
+    pub fn ad_accounts(&self, params: &ListParams) -> Paginator<'_, AdAccount> {
+        Paginator::new(self, Self::ad_accounts_request(), params)
     }
 
-    pub fn get_campaigns(&self, ad_account_id: &str) -> Result<CampaignsResponse, FacebookError> {
+    pub(crate) fn ad_accounts_request() -> GraphRequest {
+        GraphRequest::get("me/adaccounts")
+    }
 
-        let fields = vec![
-            "id", "account_id", "adlabels", "bid_strategy", "boosted_object_id", "brand_lift_studies", 
-            "budget_rebalance_flag", "budget_remaining", "buying_type", "campaign_group_active_time", 
-            "can_create_brand_lift_study", "can_use_spend_cap", "configured_status", "created_time", 
-            "daily_budget", "effective_status", "has_secondary_skadnetwork_reporting", 
-            "is_budget_schedule_enabled", "is_skadnetwork_attribution", "issues_info", 
-            "last_budget_toggling_time", "lifetime_budget", "name", "objective", "pacing_type", 
-            "primary_attribution", "promoted_object", "smart_promotion_type", "source_campaign", 
-            "source_campaign_id", "special_ad_categories", "special_ad_category", 
-            "special_ad_category_country", "spend_cap", "start_time", "status", "stop_time", 
-            "topline_id", "updated_time"// edges : "ad_studies", "adrules_governed", "ads", "adsets", 
-            //"budget_schedules", "copies", "insights"
-        ];
+    /// Fetches the first page of campaigns of an ad account.
+    ///
+    /// `params` filters and selects fields as for [`Self::campaigns`]; `max_items` is ignored.
+    pub async fn get_campaigns(&self, ad_account_id: &str, params: &ListParams) -> Result<CampaignsResponse, FacebookError> {
+        self.execute(params.apply(Self::campaigns_request(ad_account_id))).await
+    }
 
-        let fields_param = fields.join(",");
+    /// Iterates over every campaign of an ad account, following pagination.
+    pub fn campaigns(&self, ad_account_id: &str, params: &ListParams) -> Paginator<'_, Campaign> {
+        Paginator::new(self, Self::campaigns_request(ad_account_id), params)
+    }
 
-        let url = format!(
-            "https://graph.facebook.com/{}/act_{}/campaigns?access_token={}&fields={}",
-            self.version, ad_account_id, self.user_token, fields_param
-        );
+    pub(crate) fn campaigns_request(ad_account_id: &str) -> GraphRequest {
+        GraphRequest::get(format!("act_{}/campaigns", ad_account_id)).fields(&Fields::<CampaignField>::default_set())
+    }
 
-        let response = self
-            .http_client
-            .get(&url)
-            .send()
-            .map_err(|e| FacebookError::HttpError(e.to_string()))?;
+    /// Fetches one campaign with the given fields.
+    pub async fn get_campaign(&self, campaign_id: &str, fields: &Fields<CampaignField>) -> Result<Campaign, FacebookError> {
+        self.execute(Self::campaign_request(campaign_id, fields)).await
+    }
 
-        let status = response.status();
-        let response_text = response
-            .text()
-            .map_err(|e| FacebookError::Unexpected(format!("Failed to read response text: {}", e)))?;
-
-        if status.is_success() {
-            let campaigns_response: CampaignsResponse = serde_json::from_str(&response_text)
-                .map_err(|e| FacebookError::Unexpected(format!("Failed to parse JSON: {}", e)))?;
-            Ok(campaigns_response)
-        } else {
-            Err(FacebookError::HttpError(format!(
-                "Error fetching campaigns: {}. Response: {}",
-                status, response_text
-            )))
-        }
+    pub(crate) fn campaign_request(campaign_id: &str, fields: &Fields<CampaignField>) -> GraphRequest {
+        GraphRequest::get(campaign_id).fields(fields)
     }
     
-    pub fn activate_campaign(&self, campaign_id: &str) -> Result<(), FacebookError> {
-        let url = format!(
-            "https://graph.facebook.com/{}/{}?access_token={}",
-            self.version, campaign_id, self.user_token
-        );
-
-        let params = [("status", "ACTIVE")];
+    pub async fn activate_campaign(&self, campaign_id: &str) -> Result<(), FacebookError> {
+        self.execute_success(Self::activate_campaign_request(campaign_id)).await
+    }
 
-        let response = self
-            .http_client
-            .post(&url)
-            .form(&params)
-            .send()
-            .map_err(|e| FacebookError::HttpError(e.to_string()))?;
+    pub(crate) fn activate_campaign_request(campaign_id: &str) -> GraphRequest {
+        GraphRequest::post(campaign_id)
+            .form("status", "ACTIVE")
+            .idempotent(true)
+    }
 
-        let status = response.status();
-        let response_text = response
-            .text()
-            .map_err(|e| FacebookError::Unexpected(format!("Failed to read response text: {}", e)))?;
+    pub async fn delete_campaign(&self, campaign_id: &str) -> Result<(), FacebookError> {
+        self.execute_success(Self::delete_campaign_request(campaign_id)).await
+    }
 
-        if status.is_success() {
-            Ok(())
-        } else {
-            Err(FacebookError::HttpError(format!(
-                "Error activating campaign: {}. Response: {}",
-                status, response_text
-            )))
-        }
+    pub(crate) fn delete_campaign_request(campaign_id: &str) -> GraphRequest {
+        GraphRequest::delete(campaign_id)
     }
 
-    pub fn delete_campaign(&self, campaign_id: &str) -> Result<(), FacebookError> {
-        let url = format!(
-            "https://graph.facebook.com/{}/{}?access_token={}",
-            self.version, campaign_id, self.user_token
-        );
+    /// Fetches the first page of ad sets of a campaign.
+    ///
+    /// `params` filters and selects fields as for [`Self::ad_sets`]; `max_items` is ignored.
+    pub async fn get_ad_sets(&self, campaign_id: &str, params: &ListParams) -> Result<AdSetResponse, FacebookError> {
+        self.execute(params.apply(Self::ad_sets_request(campaign_id))).await
+    }
 
-        let response = self
-            .http_client
-            .delete(&url)
-            .send()
-            .map_err(|e| FacebookError::HttpError(e.to_string()))?;
+    /// Iterates over every ad set of a campaign, following pagination.
+    pub fn ad_sets(&self, campaign_id: &str, params: &ListParams) -> Paginator<'_, AdSet> {
+        Paginator::new(self, Self::ad_sets_request(campaign_id), params)
+    }
 
-        let status = response.status();
-        let response_text = response
-            .text()
-            .map_err(|e| FacebookError::Unexpected(format!("Failed to read response text: {}", e)))?;
+    pub(crate) fn ad_sets_request(campaign_id: &str) -> GraphRequest {
+        GraphRequest::get(format!("{}/adsets", campaign_id)).fields(&Fields::<AdSetField>::default_set())
+    }
 
-        if status.is_success() {
-            Ok(())
-        } else {
-            Err(FacebookError::HttpError(format!(
-                "Error deleting campaign: {}. Response: {}",
-                status, response_text
-            )))
-        }
+    /// Fetches one ad set with the given fields.
+    pub async fn get_ad_set(&self, ad_set_id: &str, fields: &Fields<AdSetField>) -> Result<AdSet, FacebookError> {
+        self.execute(Self::ad_set_request(ad_set_id, fields)).await
     }
 
-    pub fn get_ad_sets(&self, campaign_id: &str) -> Result<AdSetResponse, FacebookError> {
-
-        let fields = vec![
-            "id", "account_id", "adlabels", "adset_schedule", "asset_feed_id", "attribution_spec", 
-            "bid_adjustments", "bid_amount", "bid_constraints", "bid_info", "bid_strategy", "billing_event", 
-            "brand_safety_config", "budget_remaining", "campaign", "campaign_active_time", "campaign_attribution", 
-            "campaign_id", "configured_status",/* "contextual_bundling_spec"*/"created_time", "creative_sequence", 
-            "daily_budget", "daily_min_spend_target", "daily_spend_cap", "destination_type", "dsa_beneficiary", 
-            "dsa_payor", "effective_status", "end_time", "frequency_control_specs", "instagram_user_id", 
-            "is_dynamic_creative", "issues_info", "learning_stage_info", "lifetime_budget", "lifetime_imps", 
-            "lifetime_min_spend_target", "lifetime_spend_cap", "min_budget_spend_percentage", "multi_optimization_goal_weight", 
-            "name", "optimization_goal", "optimization_sub_event", "pacing_type", "promoted_object", "recommendations", 
-            "recurring_budget_semantics", "regional_regulated_categories", "regional_regulation_identities", "review_feedback", 
-            "rf_prediction_id", "source_adset", "source_adset_id", "start_time", "status", "targeting", 
-            "targeting_optimization_types", "time_based_ad_rotation_id_blocks", "time_based_ad_rotation_intervals", 
-            "updated_time", "use_new_app_click"
-        ];
-        
-    
-        let fields_param = fields.join(",");
-    
-        let url = format!(
-            "https://graph.facebook.com/{}/{}/adsets?fields={}&access_token={}",
-            self.version, campaign_id, fields_param, self.user_token
-        );
-    
-        let response = self
-            .http_client
-            .get(&url)
-            .send()
-            .map_err(|e| FacebookError::HttpError(e.to_string()))?;
-    
-        let status = response.status();
-        let response_text = response
-            .text()
-            .map_err(|e| FacebookError::Unexpected(format!("Failed to read response text: {}", e)))?;
-    
-        if status.is_success() {
-            let ad_set_response: AdSetResponse = serde_json::from_str(&response_text)
-                .map_err(|e| FacebookError::Unexpected(format!("Failed to parse JSON: {}", e)))?;
-            Ok(ad_set_response)
-        } else {
-            Err(FacebookError::HttpError(format!(
-                "Error fetching ad sets: {}. Response: {}",
-                status, response_text
-            )))
-        }
+    pub(crate) fn ad_set_request(ad_set_id: &str, fields: &Fields<AdSetField>) -> GraphRequest {
+        GraphRequest::get(ad_set_id).fields(fields)
     }
     
-    pub fn get_ads(&self, ad_set_id: &str) -> Result<AdResponse, FacebookError> {
-        let fields = vec![
-            "id", "account_id", "ad_active_time", "ad_review_feedback", "ad_schedule_end_time",
-            "ad_schedule_start_time", "adlabels", "adset", "adset_id", "bid_amount", "campaign",
-            "campaign_id", "configured_status", "conversion_domain", "created_time", 
-            //"creative", "creative_asset_groups_spec", 
-            "effective_status", "issues_info", "last_updated_by_app_id",
-            "name", "preview_shareable_link", "recommendations", "source_ad", "source_ad_id",
-            "status", "tracking_specs", "updated_time"
-        ];
-    
-        let fields_param = fields.join(",");
-    
-        let url = format!(
-            "https://graph.facebook.com/{}/{}/ads?fields={}&access_token={}",
-            self.version, ad_set_id, fields_param, self.user_token
-        );
-    
-        let response = self
-            .http_client
-            .get(&url)
-            .send()
-            .map_err(|e| FacebookError::HttpError(e.to_string()))?;
-    
-        let status = response.status();
-        let response_text = response
-            .text()
-            .map_err(|e| FacebookError::Unexpected(format!("Failed to read response text: {}", e)))?;
-    
-        if status.is_success() {
-            let ad_response: AdResponse = serde_json::from_str(&response_text)
-                .map_err(|e| FacebookError::Unexpected(format!("Failed to parse JSON: {}", e)))?;
-            Ok(ad_response)
-        } else {
-            Err(FacebookError::HttpError(format!(
-                "Error fetching ads: {}. Response: {}",
-                status, response_text
-            )))
-        }
+    /// Fetches the first page of ads of an ad set.
+    ///
+    /// `params` filters and selects fields as for [`Self::ads`]; `max_items` is ignored.
+    pub async fn get_ads(&self, ad_set_id: &str, params: &ListParams) -> Result<AdResponse, FacebookError> {
+        self.execute(params.apply(Self::ads_request(ad_set_id))).await
+    }
+
+    /// Iterates over every ad of an ad set, following pagination.
+    pub fn ads(&self, ad_set_id: &str, params: &ListParams) -> Paginator<'_, Ad> {
+        Paginator::new(self, Self::ads_request(ad_set_id), params)
+    }
+
+    pub(crate) fn ads_request(ad_set_id: &str) -> GraphRequest {
+        GraphRequest::get(format!("{}/ads", ad_set_id)).fields(&Fields::<AdField>::default_set())
     }
     
 }
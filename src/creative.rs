@@ -0,0 +1,231 @@
+//! Reading and creating ad creatives.
+
+use crate::client::{to_json, FacebookClient, GraphRequest};
+use crate::errors::FacebookError;
+use crate::fields::{AdCreativeField, Fields};
+use crate::pagination::{ListParams, Paginator};
+use crate::types::{
+    AdAssetFeedSpec, AdCreative, AdCreativeLinkData, AdCreativeLinkDataChildAttachment, AdCreativeObjectStorySpec,
+    AdCreativePhotoData, AdCreativeVideoData, AdCreativesResponse, CreatedObject,
+};
+
+/// Graph's limits on the number of carousel cards.
+const CAROUSEL_CARDS: std::ops::RangeInclusive<usize> = 2..=10;
+
+/// Parameters of a new ad creative.
+///
+/// The constructors cover the common formats; set the public fields directly for
+/// anything else, e.g. an `object_story_id` to promote an existing page post.
+#[derive(Debug, Clone, Default)]
+pub struct AdCreativeCreate {
+    pub name: Option<String>,
+    pub object_story_spec: Option<AdCreativeObjectStorySpec>,
+    pub object_story_id: Option<String>,
+    pub asset_feed_spec: Option<AdAssetFeedSpec>,
+    pub url_tags: Option<String>,
+    pub authorization_category: Option<String>,
+}
+
+impl AdCreativeCreate {
+    /// A single image or link ad.
+    pub fn link(name: &str, page_id: &str, link_data: AdCreativeLinkData) -> Self {
+        Self::with_story(name, page_id, |spec| spec.link_data = Some(link_data))
+    }
+
+    /// A photo post ad.
+    pub fn photo(name: &str, page_id: &str, photo_data: AdCreativePhotoData) -> Self {
+        Self::with_story(name, page_id, |spec| spec.photo_data = Some(photo_data))
+    }
+
+    /// A video ad, using a video uploaded to the ad account.
+    pub fn video(name: &str, page_id: &str, video_data: AdCreativeVideoData) -> Self {
+        Self::with_story(name, page_id, |spec| spec.video_data = Some(video_data))
+    }
+
+    /// A carousel: `link_data` carries the shared link and message, `cards` the cards.
+    pub fn carousel(
+        name: &str,
+        page_id: &str,
+        mut link_data: AdCreativeLinkData,
+        cards: Vec<AdCreativeLinkDataChildAttachment>,
+    ) -> Self {
+        link_data.child_attachments = Some(cards);
+        Self::link(name, page_id, link_data)
+    }
+
+    /// A dynamic creative. Graph picks the combination of assets to show, so the ad set
+    /// must have `is_dynamic_creative` enabled.
+    pub fn dynamic(name: &str, page_id: &str, asset_feed_spec: AdAssetFeedSpec) -> Self {
+        let mut creative = Self::with_story(name, page_id, |_| {});
+        creative.asset_feed_spec = Some(asset_feed_spec);
+        creative
+    }
+
+    fn with_story(name: &str, page_id: &str, fill: impl FnOnce(&mut AdCreativeObjectStorySpec)) -> Self {
+        let mut spec = AdCreativeObjectStorySpec {
+            page_id: Some(page_id.to_string()),
+            ..Default::default()
+        };
+        fill(&mut spec);
+        AdCreativeCreate {
+            name: Some(name.to_string()),
+            object_story_spec: Some(spec),
+            ..Default::default()
+        }
+    }
+
+    pub fn url_tags(mut self, url_tags: &str) -> Self {
+        self.url_tags = Some(url_tags.to_string());
+        self
+    }
+
+    /// Checks that exactly one of `object_story_spec` and `object_story_id` is set,
+    /// that the spec names its page and a single format, that links and carousels
+    /// have their links and 2 to 10 cards, and that photos and videos name their media.
+    pub fn validate(&self) -> Result<(), FacebookError> {
+        let spec = match (&self.object_story_spec, &self.object_story_id) {
+            (Some(_), Some(_)) => {
+                return Err(FacebookError::invalid("set either object_story_spec or object_story_id, not both"))
+            }
+            (None, None) => {
+                return Err(FacebookError::invalid("an ad creative needs an object_story_spec or an object_story_id"))
+            }
+            (None, Some(_)) if self.asset_feed_spec.is_some() => {
+                return Err(FacebookError::invalid("an asset_feed_spec needs an object_story_spec with the page"))
+            }
+            (None, Some(_)) => return Ok(()),
+            (Some(spec), None) => spec,
+        };
+        if spec.page_id.as_deref().is_none_or(str::is_empty) {
+            return Err(FacebookError::invalid("object_story_spec needs a page_id"));
+        }
+        let formats = [
+            spec.link_data.is_some(),
+            spec.photo_data.is_some(),
+            spec.video_data.is_some(),
+            spec.template_data.is_some(),
+        ]
+        .iter()
+        .filter(|set| **set)
+        .count();
+        match (&self.asset_feed_spec, formats) {
+            (Some(asset_feed_spec), 0) => return validate_asset_feed(asset_feed_spec),
+            (Some(_), _) => {
+                return Err(FacebookError::invalid(
+                    "a dynamic creative takes its assets from asset_feed_spec; leave link_data, photo_data and video_data unset",
+                ))
+            }
+            (None, 1) => {}
+            (None, _) => {
+                return Err(FacebookError::invalid(
+                    "object_story_spec needs exactly one of link_data, photo_data, video_data or template_data",
+                ))
+            }
+        }
+        if let Some(link_data) = &spec.link_data {
+            if link_data.link.as_deref().is_none_or(str::is_empty) {
+                return Err(FacebookError::invalid("link_data needs a link"));
+            }
+            if let Some(cards) = &link_data.child_attachments {
+                if !CAROUSEL_CARDS.contains(&cards.len()) {
+                    return Err(FacebookError::invalid("a carousel needs between 2 and 10 child_attachments"));
+                }
+                if cards.iter().any(|card| card.link.as_deref().is_none_or(str::is_empty)) {
+                    return Err(FacebookError::invalid("every carousel card needs a link"));
+                }
+            }
+        }
+        if let Some(photo_data) = &spec.photo_data {
+            if photo_data.image_hash.is_none() && photo_data.url.is_none() {
+                return Err(FacebookError::invalid("photo_data needs an image_hash or url"));
+            }
+        }
+        if let Some(video_data) = &spec.video_data {
+            if video_data.video_id.as_deref().is_none_or(str::is_empty) {
+                return Err(FacebookError::invalid("video_data needs a video_id"));
+            }
+            if video_data.image_hash.is_none() && video_data.image_url.is_none() {
+                return Err(FacebookError::invalid("video_data needs a thumbnail through image_hash or image_url"));
+            }
+        }
+        Ok(())
+    }
+
+    fn to_form(&self) -> Vec<(String, String)> {
+        let values = [
+            ("name", self.name.clone()),
+            ("object_story_spec", self.object_story_spec.as_ref().map(to_json)),
+            ("object_story_id", self.object_story_id.clone()),
+            ("asset_feed_spec", self.asset_feed_spec.as_ref().map(to_json)),
+            ("url_tags", self.url_tags.clone()),
+            ("authorization_category", self.authorization_category.clone()),
+        ];
+        values
+            .into_iter()
+            .filter_map(|(key, value)| value.map(|value| (key.to_string(), value)))
+            .collect()
+    }
+}
+
+fn validate_asset_feed(spec: &AdAssetFeedSpec) -> Result<(), FacebookError> {
+    let has_images = spec.images.as_ref().is_some_and(|images| !images.is_empty());
+    let has_videos = spec.videos.as_ref().is_some_and(|videos| !videos.is_empty());
+    if !has_images && !has_videos {
+        return Err(FacebookError::invalid("asset_feed_spec needs at least one image or video"));
+    }
+    if spec.bodies.as_ref().is_none_or(Vec::is_empty) {
+        return Err(FacebookError::invalid("asset_feed_spec needs at least one body"));
+    }
+    if spec.link_urls.as_ref().is_none_or(Vec::is_empty) {
+        return Err(FacebookError::invalid("asset_feed_spec needs at least one link_url"));
+    }
+    if spec.ad_formats.as_ref().is_none_or(Vec::is_empty) {
+        return Err(FacebookError::invalid("asset_feed_spec needs at least one ad_format"));
+    }
+    Ok(())
+}
+
+impl FacebookClient {
+    /// Fetches the first page of ad creatives of an ad account.
+    pub async fn get_ad_creatives(&self, ad_account_id: &str) -> Result<AdCreativesResponse, FacebookError> {
+        self.execute(Self::ad_creatives_request(ad_account_id)).await
+    }
+
+    /// Iterates over every ad creative of an ad account, following pagination.
+    pub fn ad_creatives(&self, ad_account_id: &str, params: &ListParams) -> Paginator<'_, AdCreative> {
+        Paginator::new(self, Self::ad_creatives_request(ad_account_id), params)
+    }
+
+    pub(crate) fn ad_creatives_request(ad_account_id: &str) -> GraphRequest {
+        GraphRequest::get(format!("act_{}/adcreatives", ad_account_id)).fields(&Fields::<AdCreativeField>::default_set())
+    }
+
+    /// Fetches one ad creative with the default fields.
+    pub async fn get_creative(&self, creative_id: &str) -> Result<AdCreative, FacebookError> {
+        self.execute(Self::creative_request(creative_id)).await
+    }
+
+    pub(crate) fn creative_request(creative_id: &str) -> GraphRequest {
+        GraphRequest::get(creative_id).fields(&Fields::<AdCreativeField>::default_set())
+    }
+
+    /// Creates an ad creative in an ad account and returns its ID.
+    ///
+    /// The request is validated locally first; see [`AdCreativeCreate::validate`].
+    pub async fn create_ad_creative(&self, ad_account_id: &str, creative: AdCreativeCreate) -> Result<String, FacebookError> {
+        let created: CreatedObject = self.execute(Self::create_ad_creative_request(ad_account_id, &creative)?).await?;
+        Ok(created.id)
+    }
+
+    pub(crate) fn create_ad_creative_request(
+        ad_account_id: &str,
+        creative: &AdCreativeCreate,
+    ) -> Result<GraphRequest, FacebookError> {
+        creative.validate()?;
+        let mut request = GraphRequest::post(format!("act_{}/adcreatives", ad_account_id));
+        for (key, value) in creative.to_form() {
+            request = request.form(&key, value);
+        }
+        Ok(request)
+    }
+}
@@ -1,6 +1,8 @@
+use serde::Deserialize;
+use std::fmt;
 use thiserror::Error;
 
-#[derive(Error, Debug)]
+#[derive(Error, Debug, Clone)]
 pub enum FacebookError {
     #[error("HTTP request failed with status: {0}")]
     HttpError(String),
@@ -10,4 +12,103 @@ pub enum FacebookError {
 
     #[error("Unexpected error: {0}")]
     Unexpected(String),
-}
\ No newline at end of file
+
+    #[error("Graph API error: {0}")]
+    GraphApiError(Box<GraphApiError>),
+
+    #[error("Invalid request: {0}")]
+    InvalidRequest(String),
+
+    /// An async insights job ended with `Job Failed`. Carries the report run ID.
+    #[error("Insights job {0} failed")]
+    InsightsJobFailed(String),
+
+    /// An async insights job ended with `Job Skipped`. Carries the report run ID.
+    #[error("Insights job {0} was skipped")]
+    InsightsJobSkipped(String),
+}
+
+impl FacebookError {
+    /// A request rejected locally, before it is sent.
+    pub(crate) fn invalid(message: &str) -> Self {
+        FacebookError::InvalidRequest(message.to_string())
+    }
+
+    /// Returns the structured Graph error, if this is one.
+    pub fn graph_error(&self) -> Option<&GraphApiError> {
+        match self {
+            FacebookError::GraphApiError(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+/// The `error` object of a failed Graph API response, plus the HTTP status it came with.
+///
+/// See <https://developers.facebook.com/docs/graph-api/guides/error-handling>.
+#[derive(Debug, Clone, Deserialize)]
+pub struct GraphApiError {
+    #[serde(skip)]
+    pub status: u16,
+    pub message: String,
+    #[serde(rename = "type")]
+    pub error_type: Option<String>,
+    pub code: Option<i64>,
+    pub error_subcode: Option<i64>,
+    pub fbtrace_id: Option<String>,
+    pub error_user_title: Option<String>,
+    pub error_user_msg: Option<String>,
+    /// Set by Graph when retrying the same call later may succeed.
+    pub is_transient: Option<bool>,
+}
+
+#[derive(Deserialize)]
+struct GraphErrorEnvelope {
+    error: GraphApiError,
+}
+
+impl GraphApiError {
+    /// Parses a `{"error": {...}}` response body, returning `None` if the body has another shape.
+    pub fn from_response(status: u16, body: &str) -> Option<Self> {
+        let envelope: GraphErrorEnvelope = serde_json::from_str(body).ok()?;
+        let mut error = envelope.error;
+        error.status = status;
+        Some(error)
+    }
+
+    /// Code 190: the access token is invalid or has expired.
+    pub fn is_token_expired(&self) -> bool {
+        self.code == Some(190)
+    }
+
+    /// Application, user, ad account or business use case throttling.
+    pub fn is_rate_limited(&self) -> bool {
+        matches!(self.code, Some(4 | 17 | 32 | 613 | 80000..=80014))
+    }
+
+    /// Code 100: a parameter is missing or invalid.
+    pub fn is_invalid_parameter(&self) -> bool {
+        self.code == Some(100)
+    }
+
+    /// Code 10 or 200-299: the token lacks a required permission.
+    pub fn is_permission_error(&self) -> bool {
+        matches!(self.code, Some(10 | 200..=299))
+    }
+}
+
+impl fmt::Display for GraphApiError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} (status {}", self.message, self.status)?;
+        if let Some(code) = self.code {
+            write!(f, ", code {}", code)?;
+        }
+        if let Some(subcode) = self.error_subcode {
+            write!(f, ", subcode {}", subcode)?;
+        }
+        if let Some(fbtrace_id) = &self.fbtrace_id {
+            write!(f, ", fbtrace_id {}", fbtrace_id)?;
+        }
+        write!(f, ")")
+    }
+}
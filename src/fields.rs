@@ -0,0 +1,450 @@
+//! Typed field selection for Graph reads.
+//!
+//! Graph only returns the fields named in the `fields` parameter. [`Fields`] builds
+//! that parameter from per-object field enums, including nested expansion of edges
+//! such as `adsets{name,targeting}`.
+
+use std::fmt;
+use std::marker::PhantomData;
+
+/// A field of a Graph object.
+pub trait Field: Copy + 'static {
+    /// Every field of the object this crate knows about.
+    const ALL: &'static [Self];
+    /// Fields that need permissions beyond `ads_read` and fail the whole call without them.
+    const RESTRICTED: &'static [Self];
+
+    fn name(&self) -> &'static str;
+
+    /// Fields that are safe to request with standard permissions.
+    fn default_set() -> Vec<Self> {
+        Self::ALL
+            .iter()
+            .copied()
+            .filter(|field| !Self::RESTRICTED.iter().any(|restricted| restricted.name() == field.name()))
+            .collect()
+    }
+}
+
+macro_rules! graph_fields {
+    ($(#[$meta:meta])* $name:ident { $($variant:ident => $value:literal,)* } restricted: [$($restricted:ident),*]) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+        pub enum $name {
+            $($variant,)*
+        }
+
+        impl Field for $name {
+            const ALL: &'static [Self] = &[$($name::$variant,)*];
+            const RESTRICTED: &'static [Self] = &[$($name::$restricted),*];
+
+            fn name(&self) -> &'static str {
+                match self {
+                    $($name::$variant => $value,)*
+                }
+            }
+        }
+    };
+}
+
+/// A `fields` parameter for objects whose fields are `F`.
+///
+/// ```text
+/// Fields::<CampaignField>::new()
+///     .field(CampaignField::Name)
+///     .expand("adsets", Fields::<AdSetField>::new().with(&[AdSetField::Name, AdSetField::Targeting]))
+/// ```
+/// renders as `name,adsets{name,targeting}`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Fields<F> {
+    items: Vec<String>,
+    _field: PhantomData<F>,
+}
+
+impl<F: Field> Default for Fields<F> {
+    fn default() -> Self {
+        Self::default_set()
+    }
+}
+
+impl<F: Field> Fields<F> {
+    /// An empty selection.
+    pub fn new() -> Self {
+        Fields {
+            items: Vec::new(),
+            _field: PhantomData,
+        }
+    }
+
+    /// Every field that is safe to request without extra permissions.
+    pub fn default_set() -> Self {
+        Self::new().with(&F::default_set())
+    }
+
+    /// Every known field, including restricted ones.
+    pub fn all() -> Self {
+        Self::new().with(F::ALL)
+    }
+
+    pub fn field(mut self, field: F) -> Self {
+        self.push(field.name().to_string());
+        self
+    }
+
+    pub fn with(mut self, fields: &[F]) -> Self {
+        for field in fields {
+            self.push(field.name().to_string());
+        }
+        self
+    }
+
+    /// Removes a field, e.g. to drop one from the default set.
+    pub fn without(mut self, field: F) -> Self {
+        self.items.retain(|item| item != field.name());
+        self
+    }
+
+    /// Expands an edge or object field with its own selection, as `edge{...}`.
+    pub fn expand<G: Field>(mut self, edge: &str, fields: Fields<G>) -> Self {
+        self.push(format!("{}{{{}}}", edge, fields));
+        self
+    }
+
+    /// Like [`expand`](Self::expand), limiting the number of edge items: `edge.limit(n){...}`.
+    pub fn expand_limit<G: Field>(mut self, edge: &str, limit: u32, fields: Fields<G>) -> Self {
+        self.push(format!("{}.limit({}){{{}}}", edge, limit, fields));
+        self
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    fn push(&mut self, item: String) {
+        if !self.items.contains(&item) {
+            self.items.push(item);
+        }
+    }
+}
+
+impl Fields<AdField> {
+    /// The default ad fields with each ad's `creative` expanded. Needs permission to read creatives.
+    pub fn with_creative() -> Self {
+        Self::default_set().expand("creative", Fields::<AdCreativeField>::default_set())
+    }
+}
+
+impl<F> fmt::Display for Fields<F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.items.join(","))
+    }
+}
+
+graph_fields! {
+    /// Fields of a campaign. See <https://developers.facebook.com/docs/marketing-api/reference/ad-campaign-group>.
+    ///
+    /// Edges such as `ads`, `adsets` and `insights` are requested with [`Fields::expand`].
+    CampaignField {
+        Id => "id",
+        AccountId => "account_id",
+        Adlabels => "adlabels",
+        BidStrategy => "bid_strategy",
+        BoostedObjectId => "boosted_object_id",
+        BrandLiftStudies => "brand_lift_studies",
+        BudgetRebalanceFlag => "budget_rebalance_flag",
+        BudgetRemaining => "budget_remaining",
+        BuyingType => "buying_type",
+        CampaignGroupActiveTime => "campaign_group_active_time",
+        CanCreateBrandLiftStudy => "can_create_brand_lift_study",
+        CanUseSpendCap => "can_use_spend_cap",
+        ConfiguredStatus => "configured_status",
+        CreatedTime => "created_time",
+        DailyBudget => "daily_budget",
+        EffectiveStatus => "effective_status",
+        HasSecondarySkadnetworkReporting => "has_secondary_skadnetwork_reporting",
+        IsBudgetScheduleEnabled => "is_budget_schedule_enabled",
+        IsSkadnetworkAttribution => "is_skadnetwork_attribution",
+        IssuesInfo => "issues_info",
+        LastBudgetTogglingTime => "last_budget_toggling_time",
+        LifetimeBudget => "lifetime_budget",
+        Name => "name",
+        Objective => "objective",
+        PacingType => "pacing_type",
+        PrimaryAttribution => "primary_attribution",
+        PromotedObject => "promoted_object",
+        SmartPromotionType => "smart_promotion_type",
+        SourceCampaign => "source_campaign",
+        SourceCampaignId => "source_campaign_id",
+        SpecialAdCategories => "special_ad_categories",
+        SpecialAdCategory => "special_ad_category",
+        SpecialAdCategoryCountry => "special_ad_category_country",
+        SpendCap => "spend_cap",
+        StartTime => "start_time",
+        Status => "status",
+        StopTime => "stop_time",
+        ToplineId => "topline_id",
+        UpdatedTime => "updated_time",
+    }
+    restricted: []
+}
+
+graph_fields! {
+    /// Fields of an ad set. See <https://developers.facebook.com/docs/marketing-api/reference/ad-campaign>.
+    AdSetField {
+        Id => "id",
+        AccountId => "account_id",
+        Adlabels => "adlabels",
+        AdsetSchedule => "adset_schedule",
+        AssetFeedId => "asset_feed_id",
+        AttributionSpec => "attribution_spec",
+        BidAdjustments => "bid_adjustments",
+        BidAmount => "bid_amount",
+        BidConstraints => "bid_constraints",
+        BidInfo => "bid_info",
+        BidStrategy => "bid_strategy",
+        BillingEvent => "billing_event",
+        BrandSafetyConfig => "brand_safety_config",
+        BudgetRemaining => "budget_remaining",
+        Campaign => "campaign",
+        CampaignActiveTime => "campaign_active_time",
+        CampaignAttribution => "campaign_attribution",
+        CampaignId => "campaign_id",
+        ConfiguredStatus => "configured_status",
+        ContextualBundlingSpec => "contextual_bundling_spec",
+        CreatedTime => "created_time",
+        CreativeSequence => "creative_sequence",
+        DailyBudget => "daily_budget",
+        DailyMinSpendTarget => "daily_min_spend_target",
+        DailySpendCap => "daily_spend_cap",
+        DestinationType => "destination_type",
+        DsaBeneficiary => "dsa_beneficiary",
+        DsaPayor => "dsa_payor",
+        EffectiveStatus => "effective_status",
+        EndTime => "end_time",
+        FrequencyControlSpecs => "frequency_control_specs",
+        InstagramUserId => "instagram_user_id",
+        IsDynamicCreative => "is_dynamic_creative",
+        IssuesInfo => "issues_info",
+        LearningStageInfo => "learning_stage_info",
+        LifetimeBudget => "lifetime_budget",
+        LifetimeImps => "lifetime_imps",
+        LifetimeMinSpendTarget => "lifetime_min_spend_target",
+        LifetimeSpendCap => "lifetime_spend_cap",
+        MinBudgetSpendPercentage => "min_budget_spend_percentage",
+        MultiOptimizationGoalWeight => "multi_optimization_goal_weight",
+        Name => "name",
+        OptimizationGoal => "optimization_goal",
+        OptimizationSubEvent => "optimization_sub_event",
+        PacingType => "pacing_type",
+        PromotedObject => "promoted_object",
+        Recommendations => "recommendations",
+        RecurringBudgetSemantics => "recurring_budget_semantics",
+        RegionalRegulatedCategories => "regional_regulated_categories",
+        RegionalRegulationIdentities => "regional_regulation_identities",
+        ReviewFeedback => "review_feedback",
+        RfPredictionId => "rf_prediction_id",
+        SourceAdset => "source_adset",
+        SourceAdsetId => "source_adset_id",
+        StartTime => "start_time",
+        Status => "status",
+        Targeting => "targeting",
+        TargetingOptimizationTypes => "targeting_optimization_types",
+        TimeBasedAdRotationIdBlocks => "time_based_ad_rotation_id_blocks",
+        TimeBasedAdRotationIntervals => "time_based_ad_rotation_intervals",
+        UpdatedTime => "updated_time",
+        UseNewAppClick => "use_new_app_click",
+    }
+    restricted: [ContextualBundlingSpec]
+}
+
+graph_fields! {
+    /// Fields of an ad. See <https://developers.facebook.com/docs/marketing-api/reference/adgroup>.
+    AdField {
+        Id => "id",
+        AccountId => "account_id",
+        AdActiveTime => "ad_active_time",
+        AdReviewFeedback => "ad_review_feedback",
+        AdScheduleEndTime => "ad_schedule_end_time",
+        AdScheduleStartTime => "ad_schedule_start_time",
+        Adlabels => "adlabels",
+        Adset => "adset",
+        AdsetId => "adset_id",
+        BidAmount => "bid_amount",
+        Campaign => "campaign",
+        CampaignId => "campaign_id",
+        ConfiguredStatus => "configured_status",
+        ConversionDomain => "conversion_domain",
+        CreatedTime => "created_time",
+        Creative => "creative",
+        CreativeAssetGroupsSpec => "creative_asset_groups_spec",
+        EffectiveStatus => "effective_status",
+        IssuesInfo => "issues_info",
+        LastUpdatedByAppId => "last_updated_by_app_id",
+        Name => "name",
+        PreviewShareableLink => "preview_shareable_link",
+        Recommendations => "recommendations",
+        SourceAd => "source_ad",
+        SourceAdId => "source_ad_id",
+        Status => "status",
+        TrackingSpecs => "tracking_specs",
+        UpdatedTime => "updated_time",
+    }
+    restricted: [Creative, CreativeAssetGroupsSpec]
+}
+
+graph_fields! {
+    /// Fields of an ad creative. See <https://developers.facebook.com/docs/marketing-api/reference/ad-creative>.
+    AdCreativeField {
+        Id => "id",
+        AccountId => "account_id",
+        ActorId => "actor_id",
+        AdDisclaimerSpec => "ad_disclaimer_spec",
+        Adlabels => "adlabels",
+        ApplinkTreatment => "applink_treatment",
+        AssetFeedSpec => "asset_feed_spec",
+        AuthorizationCategory => "authorization_category",
+        Body => "body",
+        BrandedContent => "branded_content",
+        BrandedContentSponsorPageId => "branded_content_sponsor_page_id",
+        BundleFolderId => "bundle_folder_id",
+        CallToActionType => "call_to_action_type",
+        CategorizationCriteria => "categorization_criteria",
+        CategoryMediaSource => "category_media_source",
+        CollaborativeAdsLsbImageBankId => "collaborative_ads_lsb_image_bank_id",
+        ContextualMultiAds => "contextual_multi_ads",
+        CreativeSourcingSpec => "creative_sourcing_spec",
+        DegreesOfFreedomSpec => "degrees_of_freedom_spec",
+        DestinationSetId => "destination_set_id",
+        DynamicAdVoice => "dynamic_ad_voice",
+        EffectiveAuthorizationCategory => "effective_authorization_category",
+        EffectiveInstagramMediaId => "effective_instagram_media_id",
+        EffectiveObjectStoryId => "effective_object_story_id",
+        EnableDirectInstall => "enable_direct_install",
+        EnableLaunchInstantApp => "enable_launch_instant_app",
+        FacebookBrandedContent => "facebook_branded_content",
+        ImageCrops => "image_crops",
+        ImageHash => "image_hash",
+        ImageUrl => "image_url",
+        InstagramPermalinkUrl => "instagram_permalink_url",
+        InstagramUserId => "instagram_user_id",
+        InteractiveComponentsSpec => "interactive_components_spec",
+        LinkDestinationDisplayUrl => "link_destination_display_url",
+        LinkOgId => "link_og_id",
+        LinkUrl => "link_url",
+        MessengerSponsoredMessage => "messenger_sponsored_message",
+        Name => "name",
+        ObjectId => "object_id",
+        ObjectStoreUrl => "object_store_url",
+        ObjectStoryId => "object_story_id",
+        ObjectStorySpec => "object_story_spec",
+        ObjectType => "object_type",
+        ObjectUrl => "object_url",
+        PageWelcomeMessage => "page_welcome_message",
+        PhotoAlbumSourceObjectStoryId => "photo_album_source_object_story_id",
+        PlacePageSetId => "place_page_set_id",
+        PlatformCustomizations => "platform_customizations",
+        PlayableAssetId => "playable_asset_id",
+        PortraitCustomizations => "portrait_customizations",
+        ProductData => "product_data",
+        ProductSetId => "product_set_id",
+        RecommenderSettings => "recommender_settings",
+        ReferralId => "referral_id",
+        RegionalRegulationDisclaimerSpec => "regional_regulation_disclaimer_spec",
+        SourceInstagramMediaId => "source_instagram_media_id",
+        Status => "status",
+        TemplateUrl => "template_url",
+        TemplateUrlSpec => "template_url_spec",
+        ThumbnailId => "thumbnail_id",
+        ThumbnailUrl => "thumbnail_url",
+        Title => "title",
+        UrlTags => "url_tags",
+        UsePageActorOverride => "use_page_actor_override",
+        VideoId => "video_id",
+    }
+    restricted: []
+}
+
+graph_fields! {
+    /// Fields of a custom audience. See <https://developers.facebook.com/docs/marketing-api/reference/custom-audience>.
+    CustomAudienceField {
+        Id => "id",
+        AccountId => "account_id",
+        Name => "name",
+        Description => "description",
+        Subtype => "subtype",
+        CustomerFileSource => "customer_file_source",
+        DataSource => "data_source",
+        ApproximateCountLowerBound => "approximate_count_lower_bound",
+        ApproximateCountUpperBound => "approximate_count_upper_bound",
+        OperationStatus => "operation_status",
+        DeliveryStatus => "delivery_status",
+        LookalikeSpec => "lookalike_spec",
+        LookalikeAudienceIds => "lookalike_audience_ids",
+        RetentionDays => "retention_days",
+        Rule => "rule",
+        IsValueBased => "is_value_based",
+        SharingStatus => "sharing_status",
+        PermissionForActions => "permission_for_actions",
+        TimeCreated => "time_created",
+        TimeUpdated => "time_updated",
+        TimeContentUpdated => "time_content_updated",
+    }
+    restricted: []
+}
+
+graph_fields! {
+    /// Metrics and dimensions of an insights report. See <https://developers.facebook.com/docs/marketing-api/reference/adgroup/insights>.
+    InsightsField {
+        AccountCurrency => "account_currency",
+        AccountId => "account_id",
+        AccountName => "account_name",
+        ActionValues => "action_values",
+        Actions => "actions",
+        AdId => "ad_id",
+        AdName => "ad_name",
+        AdsetId => "adset_id",
+        AdsetName => "adset_name",
+        BuyingType => "buying_type",
+        CampaignId => "campaign_id",
+        CampaignName => "campaign_name",
+        Clicks => "clicks",
+        ConversionRateRanking => "conversion_rate_ranking",
+        ConversionValues => "conversion_values",
+        Conversions => "conversions",
+        CostPerActionType => "cost_per_action_type",
+        CostPerConversion => "cost_per_conversion",
+        CostPerInlineLinkClick => "cost_per_inline_link_click",
+        CostPerUniqueActionType => "cost_per_unique_action_type",
+        CostPerUniqueClick => "cost_per_unique_click",
+        Cpc => "cpc",
+        Cpm => "cpm",
+        Cpp => "cpp",
+        Ctr => "ctr",
+        DateStart => "date_start",
+        DateStop => "date_stop",
+        EngagementRateRanking => "engagement_rate_ranking",
+        Frequency => "frequency",
+        Impressions => "impressions",
+        InlineLinkClickCtr => "inline_link_click_ctr",
+        InlineLinkClicks => "inline_link_clicks",
+        Objective => "objective",
+        OptimizationGoal => "optimization_goal",
+        OutboundClicks => "outbound_clicks",
+        PurchaseRoas => "purchase_roas",
+        QualityRanking => "quality_ranking",
+        Reach => "reach",
+        SocialSpend => "social_spend",
+        Spend => "spend",
+        UniqueActions => "unique_actions",
+        UniqueClicks => "unique_clicks",
+        VideoAvgTimeWatchedActions => "video_avg_time_watched_actions",
+        VideoP25WatchedActions => "video_p25_watched_actions",
+        VideoP50WatchedActions => "video_p50_watched_actions",
+        VideoP75WatchedActions => "video_p75_watched_actions",
+        VideoP100WatchedActions => "video_p100_watched_actions",
+        VideoPlayActions => "video_play_actions",
+        VideoThruplayWatchedActions => "video_thruplay_watched_actions",
+        WebsitePurchaseRoas => "website_purchase_roas",
+    }
+    restricted: []
+}
@@ -0,0 +1,156 @@
+//! Filters and date presets accepted by Graph list and reporting endpoints.
+
+use serde::Serialize;
+use serde_json::Value;
+
+/// Operators of Graph's `filtering` parameter.
+#[derive(Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum FilterOperator {
+    Equal,
+    NotEqual,
+    GreaterThan,
+    GreaterThanOrEqual,
+    LessThan,
+    LessThanOrEqual,
+    InRange,
+    NotInRange,
+    Contain,
+    NotContain,
+    In,
+    NotIn,
+    StartsWith,
+    EndsWith,
+    Any,
+    All,
+    After,
+    Before,
+    OnOrAfter,
+    OnOrBefore,
+    None,
+    Top,
+}
+
+/// One entry of the `filtering` parameter: `{"field": ..., "operator": ..., "value": ...}`.
+#[derive(Serialize, Debug, Clone, PartialEq)]
+pub struct Filter {
+    pub field: String,
+    pub operator: FilterOperator,
+    pub value: Value,
+}
+
+impl Filter {
+    pub fn new(field: &str, operator: FilterOperator, value: impl Into<Value>) -> Self {
+        Filter {
+            field: field.to_string(),
+            operator,
+            value: value.into(),
+        }
+    }
+
+    pub fn equal(field: &str, value: impl Into<Value>) -> Self {
+        Self::new(field, FilterOperator::Equal, value)
+    }
+
+    pub fn not_equal(field: &str, value: impl Into<Value>) -> Self {
+        Self::new(field, FilterOperator::NotEqual, value)
+    }
+
+    pub fn greater_than(field: &str, value: impl Into<Value>) -> Self {
+        Self::new(field, FilterOperator::GreaterThan, value)
+    }
+
+    pub fn less_than(field: &str, value: impl Into<Value>) -> Self {
+        Self::new(field, FilterOperator::LessThan, value)
+    }
+
+    pub fn contain(field: &str, value: &str) -> Self {
+        Self::new(field, FilterOperator::Contain, value)
+    }
+
+    pub fn not_contain(field: &str, value: &str) -> Self {
+        Self::new(field, FilterOperator::NotContain, value)
+    }
+
+    pub fn in_list<V: Into<Value>>(field: &str, values: impl IntoIterator<Item = V>) -> Self {
+        Self::new(field, FilterOperator::In, Value::Array(values.into_iter().map(Into::into).collect()))
+    }
+
+    pub fn not_in_list<V: Into<Value>>(field: &str, values: impl IntoIterator<Item = V>) -> Self {
+        Self::new(field, FilterOperator::NotIn, Value::Array(values.into_iter().map(Into::into).collect()))
+    }
+
+    pub fn in_range(field: &str, from: impl Into<Value>, to: impl Into<Value>) -> Self {
+        Self::new(field, FilterOperator::InRange, Value::Array(vec![from.into(), to.into()]))
+    }
+}
+
+/// Delivery states accepted by the `effective_status` parameter.
+#[derive(Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum EffectiveStatus {
+    Active,
+    Paused,
+    Deleted,
+    PendingReview,
+    Disapproved,
+    Preapproved,
+    PendingBillingInfo,
+    CampaignPaused,
+    Archived,
+    AdsetPaused,
+    InProcess,
+    WithIssues,
+}
+
+/// Relative date ranges accepted by the `date_preset` parameter.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DatePreset {
+    Today,
+    Yesterday,
+    ThisMonth,
+    LastMonth,
+    ThisQuarter,
+    Maximum,
+    DataMaximum,
+    Last3d,
+    Last7d,
+    Last14d,
+    Last28d,
+    Last30d,
+    Last90d,
+    LastWeekMonSun,
+    LastWeekSunSat,
+    LastQuarter,
+    LastYear,
+    ThisWeekMonToday,
+    ThisWeekSunToday,
+    ThisYear,
+}
+
+impl DatePreset {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            DatePreset::Today => "today",
+            DatePreset::Yesterday => "yesterday",
+            DatePreset::ThisMonth => "this_month",
+            DatePreset::LastMonth => "last_month",
+            DatePreset::ThisQuarter => "this_quarter",
+            DatePreset::Maximum => "maximum",
+            DatePreset::DataMaximum => "data_maximum",
+            DatePreset::Last3d => "last_3d",
+            DatePreset::Last7d => "last_7d",
+            DatePreset::Last14d => "last_14d",
+            DatePreset::Last28d => "last_28d",
+            DatePreset::Last30d => "last_30d",
+            DatePreset::Last90d => "last_90d",
+            DatePreset::LastWeekMonSun => "last_week_mon_sun",
+            DatePreset::LastWeekSunSat => "last_week_sun_sat",
+            DatePreset::LastQuarter => "last_quarter",
+            DatePreset::LastYear => "last_year",
+            DatePreset::ThisWeekMonToday => "this_week_mon_today",
+            DatePreset::ThisWeekSunToday => "this_week_sun_today",
+            DatePreset::ThisYear => "this_year",
+        }
+    }
+}
@@ -0,0 +1,617 @@
+//! Insights: performance metrics of ad accounts, campaigns, ad sets and ads.
+//!
+//! Small reports are read synchronously with [`FacebookClient::get_insights`]. Large
+//! date ranges or many breakdowns run as async jobs (`AdReportRun`): submit the job,
+//! poll its `async_status`, then read its rows.
+//!
+//! See <https://developers.facebook.com/docs/marketing-api/insights>.
+
+use crate::client::{to_json, FacebookClient, GraphRequest};
+use crate::errors::FacebookError;
+use crate::fields::{Fields, InsightsField};
+use crate::filtering::{DatePreset, Filter};
+use crate::pagination::{ListParams, Paginator};
+use crate::types::ListResponse;
+use serde::{Deserialize, Deserializer, Serialize};
+use serde_json::Value;
+use std::collections::HashMap;
+use std::str::FromStr;
+use std::time::{Duration, Instant};
+
+graph_enum! {
+    /// Level at which results are aggregated.
+    pub enum InsightsLevel {
+        Account => "account",
+        Campaign => "campaign",
+        AdSet => "adset",
+        Ad => "ad",
+    }
+}
+
+graph_enum! {
+    /// Dimensions that split each row of a report.
+    pub enum Breakdown {
+        Age => "age",
+        Gender => "gender",
+        Country => "country",
+        Region => "region",
+        Dma => "dma",
+        ImpressionDevice => "impression_device",
+        DevicePlatform => "device_platform",
+        PublisherPlatform => "publisher_platform",
+        PlatformPosition => "platform_position",
+        ProductId => "product_id",
+        FrequencyValue => "frequency_value",
+        HourlyStatsAggregatedByAdvertiserTimeZone => "hourly_stats_aggregated_by_advertiser_time_zone",
+        HourlyStatsAggregatedByAudienceTimeZone => "hourly_stats_aggregated_by_audience_time_zone",
+    }
+}
+
+graph_enum! {
+    /// Dimensions that split the entries of action metrics such as `actions`.
+    pub enum ActionBreakdown {
+        ActionType => "action_type",
+        ActionDevice => "action_device",
+        ActionDestination => "action_destination",
+        ActionTargetId => "action_target_id",
+        ActionReaction => "action_reaction",
+        ActionVideoSound => "action_video_sound",
+        ActionVideoType => "action_video_type",
+        ActionCarouselCardId => "action_carousel_card_id",
+        ActionCarouselCardName => "action_carousel_card_name",
+        ActionCanvasComponentName => "action_canvas_component_name",
+    }
+}
+
+graph_enum! {
+    /// Attribution windows reported alongside each action value.
+    pub enum AttributionWindow {
+        Click1d => "1d_click",
+        Click7d => "7d_click",
+        Click28d => "28d_click",
+        View1d => "1d_view",
+        View7d => "7d_view",
+        View28d => "28d_view",
+        EngagedView1d => "1d_ev",
+        Dda => "dda",
+        Default => "default",
+    }
+}
+
+/// Length of the time buckets rows are split into.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TimeIncrement {
+    /// Buckets of 1 to 90 days.
+    Days(u8),
+    Monthly,
+    /// One bucket for the whole range; Graph's default.
+    AllDays,
+}
+
+impl TimeIncrement {
+    fn to_param(self) -> String {
+        match self {
+            TimeIncrement::Days(days) => days.to_string(),
+            TimeIncrement::Monthly => "monthly".to_string(),
+            TimeIncrement::AllDays => "all_days".to_string(),
+        }
+    }
+}
+
+/// An inclusive date range in the ad account's time zone, as `YYYY-MM-DD`.
+#[derive(Serialize, Debug, Clone, PartialEq, Eq)]
+pub struct TimeRange {
+    pub since: String,
+    pub until: String,
+}
+
+impl TimeRange {
+    pub fn new(since: &str, until: &str) -> Self {
+        TimeRange {
+            since: since.to_string(),
+            until: until.to_string(),
+        }
+    }
+}
+
+/// The object a report is read from.
+#[derive(Debug, Clone, Copy)]
+pub enum InsightsObject<'a> {
+    /// An ad account ID, without the `act_` prefix.
+    AdAccount(&'a str),
+    Campaign(&'a str),
+    AdSet(&'a str),
+    Ad(&'a str),
+}
+
+impl InsightsObject<'_> {
+    pub(crate) fn insights_path(&self) -> String {
+        match self {
+            InsightsObject::AdAccount(id) => format!("act_{}/insights", id),
+            InsightsObject::Campaign(id) | InsightsObject::AdSet(id) | InsightsObject::Ad(id) => {
+                format!("{}/insights", id)
+            }
+        }
+    }
+}
+
+/// Parameters of an insights report.
+///
+/// Without a `time_range` or `date_preset`, Graph reports the last 30 days.
+#[derive(Debug, Clone)]
+pub struct InsightsParams {
+    pub level: Option<InsightsLevel>,
+    pub fields: Fields<InsightsField>,
+    pub time_range: Option<TimeRange>,
+    pub date_preset: Option<DatePreset>,
+    pub time_increment: Option<TimeIncrement>,
+    pub breakdowns: Vec<Breakdown>,
+    pub action_breakdowns: Vec<ActionBreakdown>,
+    pub action_attribution_windows: Vec<AttributionWindow>,
+    pub filtering: Vec<Filter>,
+    pub limit: Option<u32>,
+}
+
+impl Default for InsightsParams {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl InsightsParams {
+    /// Every metric in [`InsightsField`], aggregated at the level of the object read.
+    pub fn new() -> Self {
+        InsightsParams {
+            level: None,
+            fields: Fields::default_set(),
+            time_range: None,
+            date_preset: None,
+            time_increment: None,
+            breakdowns: Vec::new(),
+            action_breakdowns: Vec::new(),
+            action_attribution_windows: Vec::new(),
+            filtering: Vec::new(),
+            limit: None,
+        }
+    }
+
+    pub fn level(mut self, level: InsightsLevel) -> Self {
+        self.level = Some(level);
+        self
+    }
+
+    pub fn fields(mut self, fields: Fields<InsightsField>) -> Self {
+        self.fields = fields;
+        self
+    }
+
+    pub fn time_range(mut self, since: &str, until: &str) -> Self {
+        self.time_range = Some(TimeRange::new(since, until));
+        self
+    }
+
+    pub fn date_preset(mut self, date_preset: DatePreset) -> Self {
+        self.date_preset = Some(date_preset);
+        self
+    }
+
+    pub fn time_increment(mut self, time_increment: TimeIncrement) -> Self {
+        self.time_increment = Some(time_increment);
+        self
+    }
+
+    pub fn breakdowns(mut self, breakdowns: &[Breakdown]) -> Self {
+        self.breakdowns = breakdowns.to_vec();
+        self
+    }
+
+    pub fn action_breakdowns(mut self, action_breakdowns: &[ActionBreakdown]) -> Self {
+        self.action_breakdowns = action_breakdowns.to_vec();
+        self
+    }
+
+    pub fn action_attribution_windows(mut self, windows: &[AttributionWindow]) -> Self {
+        self.action_attribution_windows = windows.to_vec();
+        self
+    }
+
+    pub fn filter(mut self, filter: Filter) -> Self {
+        self.filtering.push(filter);
+        self
+    }
+
+    /// Rows per page.
+    pub fn limit(mut self, limit: u32) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Checks that fields are requested, that a time range and a date preset are
+    /// not both set, that the range is well-formed `YYYY-MM-DD` and in order, and
+    /// that a day increment is between 1 and 90.
+    pub fn validate(&self) -> Result<(), FacebookError> {
+        if self.fields.is_empty() {
+            return Err(FacebookError::invalid("an insights report needs at least one field"));
+        }
+        if self.time_range.is_some() && self.date_preset.is_some() {
+            return Err(FacebookError::invalid("set either time_range or date_preset, not both"));
+        }
+        if let Some(range) = &self.time_range {
+            let is_date = |date: &str| {
+                date.len() == 10
+                    && date
+                        .chars()
+                        .enumerate()
+                        .all(|(i, c)| if i == 4 || i == 7 { c == '-' } else { c.is_ascii_digit() })
+            };
+            if !is_date(&range.since) || !is_date(&range.until) {
+                return Err(FacebookError::invalid("time_range dates must be formatted as YYYY-MM-DD"));
+            }
+            if range.since > range.until {
+                return Err(FacebookError::invalid("time_range since must not be after until"));
+            }
+        }
+        if let Some(TimeIncrement::Days(days)) = self.time_increment {
+            if !(1..=90).contains(&days) {
+                return Err(FacebookError::invalid("time_increment must be between 1 and 90 days"));
+            }
+        }
+        Ok(())
+    }
+
+    pub(crate) fn apply(&self, mut request: GraphRequest) -> GraphRequest {
+        request = request.set_query("fields", self.fields.to_string());
+        if let Some(level) = self.level {
+            request = request.set_query("level", level.as_str());
+        }
+        if let Some(range) = &self.time_range {
+            request = request.set_query("time_range", to_json(range));
+        }
+        if let Some(date_preset) = self.date_preset {
+            request = request.set_query("date_preset", date_preset.as_str());
+        }
+        if let Some(time_increment) = self.time_increment {
+            request = request.set_query("time_increment", time_increment.to_param());
+        }
+        if !self.breakdowns.is_empty() {
+            request = request.set_query("breakdowns", join(&self.breakdowns, Breakdown::as_str));
+        }
+        if !self.action_breakdowns.is_empty() {
+            request = request.set_query("action_breakdowns", join(&self.action_breakdowns, ActionBreakdown::as_str));
+        }
+        if !self.action_attribution_windows.is_empty() {
+            request = request.set_query("action_attribution_windows", to_json(&self.action_attribution_windows));
+        }
+        if !self.filtering.is_empty() {
+            request = request.set_query("filtering", to_json(&self.filtering));
+        }
+        if let Some(limit) = self.limit {
+            request = request.set_query("limit", limit.to_string());
+        }
+        request
+    }
+}
+
+fn join<T>(values: &[T], name: fn(&T) -> &'static str) -> String {
+    values.iter().map(name).collect::<Vec<_>>().join(",")
+}
+
+graph_enum! {
+    /// Progress of an async insights job.
+    pub enum AsyncStatus {
+        NotStarted => "Job Not Started",
+        Started => "Job Started",
+        Running => "Job Running",
+        Completed => "Job Completed",
+        Failed => "Job Failed",
+        Skipped => "Job Skipped",
+        _ => Other(String),
+    }
+}
+
+/// An async insights job.
+#[derive(Deserialize, Debug, Clone)]
+pub struct AdReportRun {
+    pub id: String,
+    pub account_id: Option<String>,
+    pub async_status: AsyncStatus,
+    pub async_percent_completion: Option<u32>,
+    pub date_start: Option<String>,
+    pub date_stop: Option<String>,
+    pub time_ref: Option<i64>,
+    pub time_completed: Option<i64>,
+    pub is_running: Option<bool>,
+}
+
+impl AdReportRun {
+    /// Whether the job has stopped, successfully or not. A status this crate does not
+    /// know counts as still running.
+    pub fn is_finished(&self) -> bool {
+        matches!(
+            self.async_status,
+            AsyncStatus::Completed | AsyncStatus::Failed | AsyncStatus::Skipped
+        )
+    }
+
+    /// The job itself when it completed; its failure or skip as an error otherwise.
+    fn completed(self) -> Result<Self, FacebookError> {
+        match self.async_status {
+            AsyncStatus::Failed => Err(FacebookError::InsightsJobFailed(self.id)),
+            AsyncStatus::Skipped => Err(FacebookError::InsightsJobSkipped(self.id)),
+            _ => Ok(self),
+        }
+    }
+}
+
+#[derive(Deserialize, Debug)]
+struct ReportRunCreated {
+    report_run_id: String,
+}
+
+/// One entry of an action metric.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ActionValue {
+    pub value: f64,
+    /// Values per attribution window, keyed as requested, e.g. `7d_click`.
+    pub windows: HashMap<String, f64>,
+}
+
+/// An action metric keyed by `action_type`, e.g. `actions["link_click"]`.
+///
+/// With `action_breakdowns` beyond `action_type`, each key is followed by the
+/// breakdown values in breakdown-name order, separated by `/`: `link_click/mobile_app`.
+pub type ActionMap = HashMap<String, ActionValue>;
+
+pub type InsightsResponse = ListResponse<InsightsRow>;
+
+/// One row of an insights report. Graph sends numbers as strings; they are parsed here.
+#[derive(Deserialize, Debug, Clone)]
+pub struct InsightsRow {
+    pub account_id: Option<String>,
+    pub account_name: Option<String>,
+    pub account_currency: Option<String>,
+    pub campaign_id: Option<String>,
+    pub campaign_name: Option<String>,
+    pub adset_id: Option<String>,
+    pub adset_name: Option<String>,
+    pub ad_id: Option<String>,
+    pub ad_name: Option<String>,
+    pub objective: Option<String>,
+    pub optimization_goal: Option<String>,
+    pub buying_type: Option<String>,
+    pub date_start: Option<String>,
+    pub date_stop: Option<String>,
+    #[serde(default, deserialize_with = "number")]
+    pub spend: Option<f64>,
+    #[serde(default, deserialize_with = "number")]
+    pub social_spend: Option<f64>,
+    #[serde(default, deserialize_with = "number")]
+    pub impressions: Option<u64>,
+    #[serde(default, deserialize_with = "number")]
+    pub reach: Option<u64>,
+    #[serde(default, deserialize_with = "number")]
+    pub frequency: Option<f64>,
+    #[serde(default, deserialize_with = "number")]
+    pub clicks: Option<u64>,
+    #[serde(default, deserialize_with = "number")]
+    pub unique_clicks: Option<u64>,
+    #[serde(default, deserialize_with = "number")]
+    pub inline_link_clicks: Option<u64>,
+    #[serde(default, deserialize_with = "number")]
+    pub ctr: Option<f64>,
+    #[serde(default, deserialize_with = "number")]
+    pub inline_link_click_ctr: Option<f64>,
+    #[serde(default, deserialize_with = "number")]
+    pub cpc: Option<f64>,
+    #[serde(default, deserialize_with = "number")]
+    pub cpm: Option<f64>,
+    #[serde(default, deserialize_with = "number")]
+    pub cpp: Option<f64>,
+    #[serde(default, deserialize_with = "number")]
+    pub cost_per_inline_link_click: Option<f64>,
+    #[serde(default, deserialize_with = "number")]
+    pub cost_per_unique_click: Option<f64>,
+    pub quality_ranking: Option<String>,
+    pub engagement_rate_ranking: Option<String>,
+    pub conversion_rate_ranking: Option<String>,
+    #[serde(default, deserialize_with = "actions")]
+    pub actions: ActionMap,
+    #[serde(default, deserialize_with = "actions")]
+    pub unique_actions: ActionMap,
+    #[serde(default, deserialize_with = "actions")]
+    pub action_values: ActionMap,
+    #[serde(default, deserialize_with = "actions")]
+    pub cost_per_action_type: ActionMap,
+    #[serde(default, deserialize_with = "actions")]
+    pub cost_per_unique_action_type: ActionMap,
+    #[serde(default, deserialize_with = "actions")]
+    pub conversions: ActionMap,
+    #[serde(default, deserialize_with = "actions")]
+    pub conversion_values: ActionMap,
+    #[serde(default, deserialize_with = "actions")]
+    pub cost_per_conversion: ActionMap,
+    #[serde(default, deserialize_with = "actions")]
+    pub purchase_roas: ActionMap,
+    #[serde(default, deserialize_with = "actions")]
+    pub website_purchase_roas: ActionMap,
+    #[serde(default, deserialize_with = "actions")]
+    pub outbound_clicks: ActionMap,
+    #[serde(default, deserialize_with = "actions")]
+    pub video_play_actions: ActionMap,
+    #[serde(default, deserialize_with = "actions")]
+    pub video_thruplay_watched_actions: ActionMap,
+    #[serde(default, deserialize_with = "actions")]
+    pub video_p25_watched_actions: ActionMap,
+    #[serde(default, deserialize_with = "actions")]
+    pub video_p50_watched_actions: ActionMap,
+    #[serde(default, deserialize_with = "actions")]
+    pub video_p75_watched_actions: ActionMap,
+    #[serde(default, deserialize_with = "actions")]
+    pub video_p100_watched_actions: ActionMap,
+    #[serde(default, deserialize_with = "actions")]
+    pub video_avg_time_watched_actions: ActionMap,
+    /// Breakdown columns and any field not listed above, as sent by Graph.
+    #[serde(flatten)]
+    pub extra: HashMap<String, Value>,
+}
+
+impl InsightsRow {
+    /// The value of a breakdown column of this row, e.g. the `age` bucket.
+    pub fn breakdown(&self, breakdown: Breakdown) -> Option<&str> {
+        self.extra.get(breakdown.as_str()).and_then(Value::as_str)
+    }
+
+    /// Shorthand for the main value of an entry of `actions`.
+    pub fn action(&self, action_type: &str) -> Option<f64> {
+        self.actions.get(action_type).map(|action| action.value)
+    }
+}
+
+fn parse_number<T: FromStr>(value: &Value) -> Option<T> {
+    match value {
+        Value::String(text) => text.parse().ok(),
+        Value::Number(number) => number.to_string().parse().ok(),
+        _ => None,
+    }
+}
+
+fn number<'de, D, T>(deserializer: D) -> Result<Option<T>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: FromStr,
+{
+    let value = Option::<Value>::deserialize(deserializer)?;
+    match value {
+        None | Some(Value::Null) => Ok(None),
+        Some(value) => parse_number(&value)
+            .map(Some)
+            .ok_or_else(|| serde::de::Error::custom(format!("expected a number, got {}", value))),
+    }
+}
+
+fn actions<'de, D: Deserializer<'de>>(deserializer: D) -> Result<ActionMap, D::Error> {
+    let entries = Option::<Vec<serde_json::Map<String, Value>>>::deserialize(deserializer)?.unwrap_or_default();
+    let mut map = ActionMap::new();
+    for entry in entries {
+        let Some(action_type) = entry.get("action_type").and_then(Value::as_str) else {
+            continue;
+        };
+        let mut key = action_type.to_string();
+        let mut breakdowns: Vec<(&String, &Value)> = entry
+            .iter()
+            .filter(|(name, _)| name.starts_with("action_") && name.as_str() != "action_type")
+            .collect();
+        breakdowns.sort_by(|a, b| a.0.cmp(b.0));
+        for (_, value) in breakdowns {
+            key.push('/');
+            key.push_str(&value.as_str().map(str::to_string).unwrap_or_else(|| value.to_string()));
+        }
+        let mut action = ActionValue {
+            value: entry.get("value").and_then(parse_number).unwrap_or_default(),
+            windows: HashMap::new(),
+        };
+        for (name, value) in &entry {
+            if name != "value" && !name.starts_with("action_") {
+                if let Some(number) = parse_number(value) {
+                    action.windows.insert(name.clone(), number);
+                }
+            }
+        }
+        map.insert(key, action);
+    }
+    Ok(map)
+}
+
+impl FacebookClient {
+    /// Fetches the first page of an insights report.
+    pub async fn get_insights(
+        &self,
+        object: InsightsObject<'_>,
+        params: &InsightsParams,
+    ) -> Result<InsightsResponse, FacebookError> {
+        self.execute(Self::insights_request(object, params)?).await
+    }
+
+    /// Iterates over every row of an insights report, following pagination.
+    pub fn insights(
+        &self,
+        object: InsightsObject<'_>,
+        params: &InsightsParams,
+    ) -> Result<Paginator<'_, InsightsRow>, FacebookError> {
+        Ok(Paginator::new(self, Self::insights_request(object, params)?, &ListParams::new()))
+    }
+
+    pub(crate) fn insights_request(object: InsightsObject<'_>, params: &InsightsParams) -> Result<GraphRequest, FacebookError> {
+        params.validate()?;
+        Ok(params.apply(GraphRequest::get(object.insights_path())))
+    }
+
+    /// Starts an async insights job and returns its report run ID.
+    pub async fn submit_insights_job(&self, object: InsightsObject<'_>, params: &InsightsParams) -> Result<String, FacebookError> {
+        params.validate()?;
+        let request = params.apply(GraphRequest::post(object.insights_path()));
+        let created: ReportRunCreated = self.execute(request).await?;
+        Ok(created.report_run_id)
+    }
+
+    /// Reads the current state of an async insights job.
+    ///
+    /// A job that ended with `Job Failed` or `Job Skipped` is returned as
+    /// [`FacebookError::InsightsJobFailed`] or [`FacebookError::InsightsJobSkipped`].
+    pub async fn poll_job(&self, report_run_id: &str) -> Result<AdReportRun, FacebookError> {
+        let job: AdReportRun = self.execute(GraphRequest::get(report_run_id)).await?;
+        job.completed()
+    }
+
+    /// Polls an async insights job until it completes.
+    ///
+    /// Keeps polling through statuses this crate does not know. Fails with the job's
+    /// typed error when it fails or is skipped, and with `Unexpected` when it is still
+    /// running after `timeout`.
+    pub async fn wait_for_job(
+        &self,
+        report_run_id: &str,
+        poll_interval: Duration,
+        timeout: Duration,
+    ) -> Result<AdReportRun, FacebookError> {
+        let deadline = Instant::now() + timeout;
+        loop {
+            let job = self.poll_job(report_run_id).await?;
+            if job.is_finished() {
+                return Ok(job);
+            }
+            if Instant::now() + poll_interval > deadline {
+                return Err(FacebookError::Unexpected(format!(
+                    "Insights job {} was not finished after {:?} ({}, {}%)",
+                    report_run_id,
+                    timeout,
+                    job.async_status,
+                    job.async_percent_completion.unwrap_or_default()
+                )));
+            }
+            tokio::time::sleep(poll_interval).await;
+        }
+    }
+
+    /// Iterates over the rows of a completed async insights job, following pagination.
+    pub fn fetch_job_results(&self, report_run_id: &str, params: &ListParams) -> Paginator<'_, InsightsRow> {
+        Paginator::new(self, GraphRequest::get(format!("{}/insights", report_run_id)), params)
+    }
+
+    /// Runs an insights report as an async job: submits it, waits for it and reads every row.
+    pub async fn run_insights_job(
+        &self,
+        object: InsightsObject<'_>,
+        params: &InsightsParams,
+        poll_interval: Duration,
+        timeout: Duration,
+    ) -> Result<Vec<InsightsRow>, FacebookError> {
+        let report_run_id = self.submit_insights_job(object, params).await?;
+        self.wait_for_job(&report_run_id, poll_interval, timeout).await?;
+        let mut list_params = ListParams::new();
+        if let Some(limit) = params.limit {
+            list_params = list_params.limit(limit);
+        }
+        self.fetch_job_results(&report_run_id, &list_params).collect_all().await
+    }
+}
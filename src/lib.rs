@@ -0,0 +1,30 @@
+//! Rust client for the Facebook Marketing (Graph) API.
+//!
+//! [`client::FacebookClient`] is the async client built on `tokio` and `reqwest`.
+//! Scripts that do not run inside a runtime can use [`blocking::FacebookClient`],
+//! which wraps the async client and drives it on its own runtime.
+
+#[macro_use]
+mod macros;
+
+pub mod ad;
+pub mod adset;
+pub mod audience;
+pub mod batch;
+pub mod blocking;
+pub mod builder;
+pub mod campaign;
+pub mod client;
+pub mod creative;
+pub mod errors;
+pub mod fields;
+pub mod filtering;
+pub mod insights;
+pub mod media;
+pub mod pagination;
+pub mod retry;
+pub mod search;
+pub mod secret;
+pub mod token;
+pub mod types;
+pub mod usage;
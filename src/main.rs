@@ -1,17 +1,15 @@
-mod client;
-mod errors;
-mod types;
+use fbappv2::client::FacebookClient;
+use fbappv2::errors::*;
+use fbappv2::pagination::ListParams;
 
-use crate::client::FacebookClient;
-use crate::errors::*;
-
-fn main() {
+#[tokio::main]
+async fn main() {
     let app_id = "your_app_id_here";  // Replace with your actual app ID
     let app_secret = "your_app_secret_here";  // Replace with your actual app secret
     let user_token = "your_user_token_here";  // Replace with your actual user token
     let version = "v22.0"; 
 
-    let client = match FacebookClient::new(app_id, app_secret, user_token, version) {
+    let client = match FacebookClient::new(app_id, app_secret, user_token, version).await {
         Ok(client) => client,
         Err(e) => {
             eprintln!("Error creating Facebook client: {}", e);
@@ -19,7 +17,7 @@ fn main() {
         }
     };
 
-    match client.get_ad_accounts() {
+    match client.get_ad_accounts().await {
         Ok(ad_accounts_response) => {
             ad_accounts_response.display();
         }
@@ -30,28 +28,38 @@ fn main() {
 
     let campaign_id = "your_campaign_id_here";  // Replace with your actual campaign ID
 
-    match client.activate_campaign(campaign_id) {
+    match client.activate_campaign(campaign_id).await {
         Ok(()) => println!("The campaign with ID {} has been successfully activated!", campaign_id),
         Err(e) => match e {
             FacebookError::HttpError(err_msg) => println!("HTTP Error: {}", err_msg),
             FacebookError::Unauthorized => println!("Error: You don't have enough permissions to activate the campaign."),
             FacebookError::Unexpected(msg) => println!("Unexpected error: {}", msg),
+            FacebookError::GraphApiError(err) => println!("Graph API error: {}", err),
+            FacebookError::InvalidRequest(msg) => println!("Invalid request: {}", msg),
+            FacebookError::InsightsJobFailed(id) | FacebookError::InsightsJobSkipped(id) => {
+                println!("Insights job {} did not complete", id)
+            }
         },
     }
 
     let campaign_id = "your_campaign_id_to_delete_here";  // Replace with the campaign ID you want to delete
-    match client.delete_campaign(campaign_id) {
+    match client.delete_campaign(campaign_id).await {
         Ok(()) => println!("The campaign with ID {} has been successfully deleted!", campaign_id),
         Err(e) => match e {
             FacebookError::HttpError(err_msg) => println!("HTTP Error: {}", err_msg),
             FacebookError::Unauthorized => println!("Error: You don't have enough permissions to delete the campaign."),
             FacebookError::Unexpected(msg) => println!("Unexpected error: {}", msg),
+            FacebookError::GraphApiError(err) => println!("Graph API error: {}", err),
+            FacebookError::InvalidRequest(msg) => println!("Invalid request: {}", msg),
+            FacebookError::InsightsJobFailed(id) | FacebookError::InsightsJobSkipped(id) => {
+                println!("Insights job {} did not complete", id)
+            }
         },
     }
     
     let ad_account_id = "your_ad_account_id_here";  // Replace with your actual ad account ID
 
-    match client.get_campaigns(ad_account_id) {
+    match client.get_campaigns(ad_account_id, &ListParams::new()).await {
         Ok(campaigns_response) => {
             campaigns_response.display();
         }
@@ -62,7 +70,7 @@ fn main() {
 
     let campaign_id: &str = "your_campaign_id_here";  // Replace with the specific campaign ID you want to use
 
-    match client.get_ad_sets(campaign_id) {
+    match client.get_ad_sets(campaign_id, &ListParams::new()).await {
         Ok(ad_sets_response) => {
             ad_sets_response.display();
         }
@@ -0,0 +1,371 @@
+//! Uploading ad images and videos.
+//!
+//! Images go to `act_<id>/adimages` in one multipart request. Videos use Graph's
+//! chunked protocol against `video_base_url`: a `start` phase opens an upload session,
+//! `transfer` sends the byte range Graph asks for next, and `finish` publishes the
+//! video. Files are streamed from disk one part at a time and never read into memory
+//! whole.
+//!
+//! See <https://developers.facebook.com/docs/marketing-api/ad-creative/asset-feed-spec/upload-video>.
+
+use crate::client::{FacebookClient, GraphRequest};
+use crate::errors::FacebookError;
+use reqwest::multipart::{Form, Part};
+use reqwest::Body;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fmt;
+use std::io::SeekFrom;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tokio::io::{AsyncReadExt, AsyncSeekExt};
+use tokio_util::io::ReaderStream;
+
+/// Where an uploaded file comes from.
+#[derive(Debug, Clone)]
+pub enum MediaSource {
+    /// A file on disk, streamed while it is sent.
+    Path(PathBuf),
+    /// Bytes already in memory, sent under `file_name`.
+    Bytes { file_name: String, bytes: Vec<u8> },
+}
+
+impl MediaSource {
+    pub fn path(path: impl Into<PathBuf>) -> Self {
+        MediaSource::Path(path.into())
+    }
+
+    pub fn bytes(file_name: &str, bytes: Vec<u8>) -> Self {
+        MediaSource::Bytes {
+            file_name: file_name.to_string(),
+            bytes,
+        }
+    }
+}
+
+/// An image in the ad account's library, referenced from creatives by `hash`.
+#[derive(Deserialize, Debug, Clone)]
+pub struct AdImage {
+    pub hash: String,
+    pub url: Option<String>,
+    pub name: Option<String>,
+}
+
+#[derive(Deserialize, Debug)]
+struct AdImageUploadResponse {
+    images: HashMap<String, AdImage>,
+}
+
+/// Progress of a chunked video upload.
+///
+/// The session can be stored and handed back to
+/// [`FacebookClient::continue_video_upload`] to resume after a failure, including
+/// from another process.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct VideoUploadSession {
+    pub upload_session_id: String,
+    pub video_id: String,
+    pub file_size: u64,
+    /// First byte of the next chunk Graph expects.
+    pub start_offset: u64,
+    /// End (exclusive) of the next chunk Graph expects.
+    pub end_offset: u64,
+}
+
+impl VideoUploadSession {
+    /// Whether every byte has been transferred and the upload can be finished.
+    pub fn is_transferred(&self) -> bool {
+        self.start_offset >= self.end_offset
+    }
+}
+
+/// Offsets come back as strings.
+#[derive(Deserialize, Debug)]
+struct ChunkOffsets {
+    start_offset: String,
+    end_offset: String,
+}
+
+#[derive(Deserialize, Debug)]
+struct StartResponse {
+    upload_session_id: String,
+    video_id: String,
+    #[serde(flatten)]
+    offsets: ChunkOffsets,
+}
+
+graph_enum! {
+    /// `video_status` of an uploaded video.
+    pub enum VideoState {
+        Ready => "ready",
+        Processing => "processing",
+        Error => "error",
+        Expired => "expired",
+        UploadFailed => "upload_failed",
+        _ => Other(String),
+    }
+}
+
+/// Processing state of an uploaded video, from its `status` field.
+#[derive(Deserialize, Debug, Clone)]
+pub struct VideoStatus {
+    pub video_status: VideoState,
+    pub processing_progress: Option<u32>,
+    pub uploading_phase: Option<serde_json::Value>,
+    pub processing_phase: Option<serde_json::Value>,
+    pub publishing_phase: Option<serde_json::Value>,
+}
+
+impl VideoStatus {
+    pub fn is_ready(&self) -> bool {
+        self.video_status == VideoState::Ready
+    }
+
+    /// Whether the video will not become ready: any state but `ready` and `processing`.
+    pub fn has_failed(&self) -> bool {
+        !matches!(self.video_status, VideoState::Ready | VideoState::Processing)
+    }
+}
+
+#[derive(Deserialize, Debug)]
+struct VideoStatusResponse {
+    status: VideoStatus,
+}
+
+/// A file sent as one part of a multipart request. It is opened again on every
+/// attempt, so retries do not need the file in memory.
+#[derive(Debug, Clone)]
+pub(crate) struct FilePart {
+    pub name: String,
+    pub file_name: String,
+    pub source: FileSource,
+}
+
+#[derive(Clone)]
+pub(crate) enum FileSource {
+    /// `len` bytes of a file on disk, starting at `offset`.
+    Path { path: PathBuf, offset: u64, len: u64 },
+    Bytes(Arc<Vec<u8>>),
+}
+
+impl fmt::Debug for FileSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FileSource::Path { path, offset, len } => f
+                .debug_struct("Path")
+                .field("path", path)
+                .field("offset", offset)
+                .field("len", len)
+                .finish(),
+            FileSource::Bytes(bytes) => write!(f, "Bytes({} bytes)", bytes.len()),
+        }
+    }
+}
+
+impl FilePart {
+    async fn to_part(&self) -> Result<Part, FacebookError> {
+        let part = match &self.source {
+            FileSource::Bytes(bytes) => Part::bytes(bytes.as_ref().clone()),
+            FileSource::Path { path, offset, len } => {
+                let mut file = tokio::fs::File::open(path).await.map_err(|e| io_error(path, e))?;
+                file.seek(SeekFrom::Start(*offset)).await.map_err(|e| io_error(path, e))?;
+                let stream = ReaderStream::new(file.take(*len));
+                Part::stream_with_length(Body::wrap_stream(stream), *len)
+            }
+        };
+        Ok(part.file_name(self.file_name.clone()))
+    }
+}
+
+/// Builds the multipart body of a request with files: the form fields as text parts, then the files.
+pub(crate) async fn multipart_form(fields: &[(String, String)], files: &[FilePart]) -> Result<Form, FacebookError> {
+    let mut form = Form::new();
+    for (key, value) in fields {
+        form = form.text(key.clone(), value.clone());
+    }
+    for file in files {
+        form = form.part(file.name.clone(), file.to_part().await?);
+    }
+    Ok(form)
+}
+
+fn io_error(path: &Path, error: std::io::Error) -> FacebookError {
+    FacebookError::Unexpected(format!("Failed to read {}: {}", path.display(), error))
+}
+
+fn file_name_of(path: &Path) -> String {
+    path.file_name()
+        .map(|name| name.to_string_lossy().into_owned())
+        .unwrap_or_else(|| "upload".to_string())
+}
+
+fn parse_offset(value: &str) -> Result<u64, FacebookError> {
+    value
+        .parse()
+        .map_err(|_| FacebookError::Unexpected(format!("Invalid upload offset from Graph: {}", value)))
+}
+
+impl FacebookClient {
+    /// Uploads an image to the ad account's library and returns its hash and URL.
+    pub async fn upload_ad_image(&self, ad_account_id: &str, source: MediaSource) -> Result<AdImage, FacebookError> {
+        let (file_name, source) = match source {
+            MediaSource::Path(path) => {
+                let len = tokio::fs::metadata(&path).await.map_err(|e| io_error(&path, e))?.len();
+                (file_name_of(&path), FileSource::Path { path, offset: 0, len })
+            }
+            MediaSource::Bytes { file_name, bytes } => (file_name, FileSource::Bytes(Arc::new(bytes))),
+        };
+        // Graph deduplicates images by content, so a repeated upload returns the same hash.
+        let request = GraphRequest::post(format!("act_{}/adimages", ad_account_id))
+            .file(FilePart {
+                name: "filename".to_string(),
+                file_name,
+                source,
+            })
+            .idempotent(true);
+        let response: AdImageUploadResponse = self.execute(request).await?;
+        response
+            .images
+            .into_iter()
+            .next()
+            .map(|(name, mut image)| {
+                image.name.get_or_insert(name);
+                image
+            })
+            .ok_or_else(|| FacebookError::Unexpected("Graph returned no image for the upload".to_string()))
+    }
+
+    /// Uploads a video from disk in chunks, finishes the upload and returns the video ID.
+    ///
+    /// Each chunk is retried according to the client's retry policy. For control over
+    /// resuming, use [`start_video_upload`](Self::start_video_upload),
+    /// [`continue_video_upload`](Self::continue_video_upload) and
+    /// [`finish_video_upload`](Self::finish_video_upload) directly. Graph processes the
+    /// video after the upload; see [`wait_for_video`](Self::wait_for_video).
+    pub async fn upload_ad_video(&self, ad_account_id: &str, path: &Path, title: Option<&str>) -> Result<String, FacebookError> {
+        let mut session = self.start_video_upload(ad_account_id, path).await?;
+        self.continue_video_upload(ad_account_id, &mut session, path).await?;
+        self.finish_video_upload(ad_account_id, &session, title).await?;
+        Ok(session.video_id)
+    }
+
+    /// Opens an upload session for a video file.
+    pub async fn start_video_upload(&self, ad_account_id: &str, path: &Path) -> Result<VideoUploadSession, FacebookError> {
+        let file_size = tokio::fs::metadata(path).await.map_err(|e| io_error(path, e))?.len();
+        let request = GraphRequest::post(self.video_upload_url(ad_account_id))
+            .form("upload_phase", "start")
+            .form("file_size", file_size.to_string());
+        let response: StartResponse = self.execute(request).await?;
+        Ok(VideoUploadSession {
+            upload_session_id: response.upload_session_id,
+            video_id: response.video_id,
+            file_size,
+            start_offset: parse_offset(&response.offsets.start_offset)?,
+            end_offset: parse_offset(&response.offsets.end_offset)?,
+        })
+    }
+
+    /// Sends the chunks Graph still expects, updating `session` after each one.
+    ///
+    /// When a chunk fails for good, `session` still points at it, so calling this
+    /// again with the same session resumes the upload there.
+    pub async fn continue_video_upload(
+        &self,
+        ad_account_id: &str,
+        session: &mut VideoUploadSession,
+        path: &Path,
+    ) -> Result<(), FacebookError> {
+        let file_name = file_name_of(path);
+        while !session.is_transferred() {
+            // The chunk is identified by its offset, so sending it again is harmless.
+            let request = GraphRequest::post(self.video_upload_url(ad_account_id))
+                .form("upload_phase", "transfer")
+                .form("upload_session_id", session.upload_session_id.clone())
+                .form("start_offset", session.start_offset.to_string())
+                .file(FilePart {
+                    name: "video_file_chunk".to_string(),
+                    file_name: file_name.clone(),
+                    source: FileSource::Path {
+                        path: path.to_path_buf(),
+                        offset: session.start_offset,
+                        len: session.end_offset - session.start_offset,
+                    },
+                })
+                .idempotent(true);
+            let offsets: ChunkOffsets = self.execute(request).await?;
+            session.start_offset = parse_offset(&offsets.start_offset)?;
+            session.end_offset = parse_offset(&offsets.end_offset)?;
+        }
+        Ok(())
+    }
+
+    /// Closes a fully transferred upload session and publishes the video.
+    pub async fn finish_video_upload(
+        &self,
+        ad_account_id: &str,
+        session: &VideoUploadSession,
+        title: Option<&str>,
+    ) -> Result<(), FacebookError> {
+        if !session.is_transferred() {
+            return Err(FacebookError::InvalidRequest(format!(
+                "the upload stopped at byte {} of {}; continue it before finishing",
+                session.start_offset, session.file_size
+            )));
+        }
+        let mut request = GraphRequest::post(self.video_upload_url(ad_account_id))
+            .form("upload_phase", "finish")
+            .form("upload_session_id", session.upload_session_id.clone())
+            .idempotent(true);
+        if let Some(title) = title {
+            request = request.form("title", title);
+        }
+        self.execute_success(request).await
+    }
+
+    /// Fetches the processing state of a video.
+    pub async fn get_video_status(&self, video_id: &str) -> Result<VideoStatus, FacebookError> {
+        let response: VideoStatusResponse = self
+            .execute(GraphRequest::get(video_id).query("fields", "status"))
+            .await?;
+        Ok(response.status)
+    }
+
+    /// Polls a video's `status.video_status` until Graph reports it ready.
+    ///
+    /// Fails as soon as [`VideoStatus::has_failed`], e.g. on `error`, `expired` or
+    /// `upload_failed`, or when processing does not finish within `timeout`.
+    pub async fn wait_for_video(
+        &self,
+        video_id: &str,
+        poll_interval: Duration,
+        timeout: Duration,
+    ) -> Result<VideoStatus, FacebookError> {
+        let deadline = Instant::now() + timeout;
+        loop {
+            let status = self.get_video_status(video_id).await?;
+            if status.is_ready() {
+                return Ok(status);
+            }
+            if status.has_failed() {
+                return Err(FacebookError::Unexpected(format!(
+                    "Video {} failed with status {}: {}",
+                    video_id,
+                    status.video_status,
+                    status.processing_phase.map(|phase| phase.to_string()).unwrap_or_default()
+                )));
+            }
+            if Instant::now() + poll_interval > deadline {
+                return Err(FacebookError::Unexpected(format!(
+                    "Video {} was not ready after {:?} (status: {})",
+                    video_id, timeout, status.video_status
+                )));
+            }
+            tokio::time::sleep(poll_interval).await;
+        }
+    }
+
+    fn video_upload_url(&self, ad_account_id: &str) -> String {
+        format!("{}/{}/act_{}/advideos", self.video_base_url, self.version, ad_account_id)
+    }
+}
@@ -0,0 +1,189 @@
+//! Cursor pagination over Graph list edges.
+//!
+//! A [`Paginator`] sends the first request of a list edge, then keeps following
+//! `paging.next` until Graph stops returning one or the configured item cap is reached.
+
+use crate::client::{to_json, FacebookClient, GraphRequest};
+use crate::errors::FacebookError;
+use crate::fields::{Field, Fields};
+use crate::filtering::{DatePreset, EffectiveStatus, Filter};
+use crate::types::ListResponse;
+use serde::de::DeserializeOwned;
+use std::collections::VecDeque;
+
+/// Options shared by every list endpoint: page size, field selection and filters.
+#[derive(Debug, Clone, Default)]
+pub struct ListParams {
+    /// Page size requested from Graph (`limit`). Graph's default is 25.
+    pub limit: Option<u32>,
+    /// Stop after this many items in total, across pages.
+    pub max_items: Option<usize>,
+    /// Rendered `fields` parameter, replacing the endpoint's default selection.
+    pub fields: Option<String>,
+    pub filtering: Vec<Filter>,
+    pub effective_status: Vec<EffectiveStatus>,
+    /// Unix time; only objects updated after it are returned.
+    pub updated_since: Option<i64>,
+    pub date_preset: Option<DatePreset>,
+    /// Additional raw query parameters.
+    pub extra: Vec<(String, String)>,
+}
+
+impl ListParams {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn limit(mut self, limit: u32) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    pub fn max_items(mut self, max_items: usize) -> Self {
+        self.max_items = Some(max_items);
+        self
+    }
+
+    /// Requests only these fields instead of the endpoint's default set.
+    pub fn fields<F: Field>(mut self, fields: Fields<F>) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+
+    pub fn filter(mut self, filter: Filter) -> Self {
+        self.filtering.push(filter);
+        self
+    }
+
+    pub fn effective_status(mut self, statuses: &[EffectiveStatus]) -> Self {
+        self.effective_status.extend_from_slice(statuses);
+        self
+    }
+
+    pub fn updated_since(mut self, unix_time: i64) -> Self {
+        self.updated_since = Some(unix_time);
+        self
+    }
+
+    pub fn date_preset(mut self, date_preset: DatePreset) -> Self {
+        self.date_preset = Some(date_preset);
+        self
+    }
+
+    /// Sets a query parameter this type does not model.
+    pub fn param(mut self, key: &str, value: impl Into<String>) -> Self {
+        self.extra.push((key.to_string(), value.into()));
+        self
+    }
+
+    /// Adds the query parameters of these options to the first request of a list.
+    pub(crate) fn apply(&self, mut request: GraphRequest) -> GraphRequest {
+        if let Some(fields) = &self.fields {
+            request = request.set_query("fields", fields.as_str());
+        }
+        if !self.filtering.is_empty() {
+            request = request.set_query("filtering", to_json(&self.filtering));
+        }
+        if !self.effective_status.is_empty() {
+            request = request.set_query("effective_status", to_json(&self.effective_status));
+        }
+        if let Some(updated_since) = self.updated_since {
+            request = request.set_query("updated_since", updated_since.to_string());
+        }
+        if let Some(date_preset) = self.date_preset {
+            request = request.set_query("date_preset", date_preset.as_str());
+        }
+        for (key, value) in &self.extra {
+            request = request.set_query(key, value.as_str());
+        }
+        if let Some(limit) = self.limit {
+            request = request.query("limit", limit.to_string());
+        }
+        request
+    }
+}
+
+/// Lazily walks every page of a list edge.
+///
+/// Items are yielded one at a time with [`Paginator::next`], or page by page with
+/// [`Paginator::next_page`]. Only one page is held in memory at a time.
+#[derive(Debug)]
+pub struct Paginator<'a, T> {
+    client: &'a FacebookClient,
+    next_request: Option<GraphRequest>,
+    buffer: VecDeque<T>,
+    remaining: Option<usize>,
+}
+
+impl<'a, T: DeserializeOwned> Paginator<'a, T> {
+    pub(crate) fn new(client: &'a FacebookClient, request: GraphRequest, params: &ListParams) -> Self {
+        Paginator {
+            client,
+            next_request: Some(params.apply(request)),
+            buffer: VecDeque::new(),
+            remaining: params.max_items,
+        }
+    }
+
+    /// Fetches the next page, returning `None` once every page has been read.
+    ///
+    /// Pages are truncated so that no more than `max_items` items are returned overall.
+    pub async fn next_page(&mut self) -> Option<Result<Vec<T>, FacebookError>> {
+        if let Some(items) = self.take_buffered() {
+            return Some(Ok(items));
+        }
+        if self.remaining == Some(0) {
+            return None;
+        }
+        let request = self.next_request.take()?;
+
+        let page: ListResponse<T> = match self.client.execute(request).await {
+            Ok(page) => page,
+            Err(e) => return Some(Err(e)),
+        };
+
+        if let Some(url) = page.next_page_url() {
+            match GraphRequest::from_url(url) {
+                Ok(request) => self.next_request = Some(request),
+                Err(e) => return Some(Err(e)),
+            }
+        }
+
+        let mut items = page.data;
+        if let Some(remaining) = self.remaining.as_mut() {
+            items.truncate(*remaining);
+            *remaining -= items.len();
+        }
+        Some(Ok(items))
+    }
+
+    /// Returns the next item, fetching another page when the current one is exhausted.
+    pub async fn next(&mut self) -> Option<Result<T, FacebookError>> {
+        loop {
+            if let Some(item) = self.buffer.pop_front() {
+                return Some(Ok(item));
+            }
+            match self.next_page().await? {
+                Ok(items) => self.buffer.extend(items),
+                Err(e) => return Some(Err(e)),
+            }
+        }
+    }
+
+    /// Reads every remaining page and returns all items.
+    pub async fn collect_all(mut self) -> Result<Vec<T>, FacebookError> {
+        let mut items = Vec::new();
+        while let Some(page) = self.next_page().await {
+            items.extend(page?);
+        }
+        Ok(items)
+    }
+
+    fn take_buffered(&mut self) -> Option<Vec<T>> {
+        if self.buffer.is_empty() {
+            None
+        } else {
+            Some(self.buffer.drain(..).collect())
+        }
+    }
+}
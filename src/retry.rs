@@ -0,0 +1,135 @@
+//! Retry policy for transient Graph API failures.
+
+use std::collections::hash_map::RandomState;
+use std::hash::{BuildHasher, Hasher};
+use std::time::Duration;
+
+/// Graph error codes that signal throttling. A throttled call was rejected
+/// before it was applied, so it is always safe to send again.
+pub const DEFAULT_RETRYABLE_CODES: &[i64] = &[
+    4, 17, 32, 613, 80000, 80001, 80002, 80003, 80004, 80005, 80006, 80007, 80008, 80009, 80010,
+    80011, 80012, 80013, 80014,
+];
+
+/// How a failed attempt may be retried.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Retryability {
+    /// The failure is permanent (bad parameter, missing permission, ...).
+    Never,
+    /// The call may or may not have been applied (timeout, 5xx, transient Graph error).
+    IfIdempotent,
+    /// The call was certainly not applied (throttled, connection refused).
+    Always,
+}
+
+/// Controls how many times, and how far apart, a failed request is retried.
+///
+/// Delays grow exponentially from `base_delay` and are capped at `max_delay`.
+/// With `jitter` enabled each delay is drawn from the upper half of that range
+/// so that concurrent jobs do not retry in lockstep.
+///
+/// Non-idempotent calls (creates, deletes) are only retried when the failure
+/// proves the call was not applied, unless `retry_non_idempotent` is set.
+#[derive(Debug, Clone)]
+pub struct RetryPolicy {
+    /// Total number of attempts, including the first one. `1` disables retries.
+    pub max_attempts: u32,
+    pub base_delay: Duration,
+    pub max_delay: Duration,
+    pub jitter: bool,
+    /// Graph error codes that are retried regardless of idempotency.
+    pub retryable_codes: Vec<i64>,
+    pub retry_non_idempotent: bool,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        RetryPolicy {
+            max_attempts: 3,
+            base_delay: Duration::from_secs(1),
+            max_delay: Duration::from_secs(60),
+            jitter: true,
+            retryable_codes: DEFAULT_RETRYABLE_CODES.to_vec(),
+            retry_non_idempotent: false,
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// A policy that never retries.
+    pub fn none() -> Self {
+        RetryPolicy {
+            max_attempts: 1,
+            ..Self::default()
+        }
+    }
+
+    pub fn max_attempts(mut self, max_attempts: u32) -> Self {
+        self.max_attempts = max_attempts.max(1);
+        self
+    }
+
+    pub fn base_delay(mut self, base_delay: Duration) -> Self {
+        self.base_delay = base_delay;
+        self
+    }
+
+    pub fn max_delay(mut self, max_delay: Duration) -> Self {
+        self.max_delay = max_delay;
+        self
+    }
+
+    pub fn jitter(mut self, jitter: bool) -> Self {
+        self.jitter = jitter;
+        self
+    }
+
+    pub fn retryable_codes(mut self, codes: Vec<i64>) -> Self {
+        self.retryable_codes = codes;
+        self
+    }
+
+    pub fn retry_non_idempotent(mut self, retry_non_idempotent: bool) -> Self {
+        self.retry_non_idempotent = retry_non_idempotent;
+        self
+    }
+
+    /// Whether a Graph error code is treated as throttling.
+    pub fn is_retryable_code(&self, code: i64) -> bool {
+        self.retryable_codes.contains(&code)
+    }
+
+    /// Decides whether the attempt numbered `attempt` (starting at 1) should be followed by another.
+    pub(crate) fn should_retry(&self, retryability: Retryability, idempotent: bool, attempt: u32) -> bool {
+        if attempt >= self.max_attempts {
+            return false;
+        }
+        match retryability {
+            Retryability::Never => false,
+            Retryability::IfIdempotent => idempotent || self.retry_non_idempotent,
+            Retryability::Always => true,
+        }
+    }
+
+    /// Delay to wait after the failed attempt numbered `attempt` (starting at 1).
+    pub fn backoff(&self, attempt: u32) -> Duration {
+        let exponent = attempt.saturating_sub(1).min(31);
+        let delay = self
+            .base_delay
+            .saturating_mul(1u32 << exponent)
+            .min(self.max_delay);
+        if !self.jitter {
+            return delay;
+        }
+        let half = delay / 2;
+        let nanos = half.as_nanos() as u64;
+        if nanos == 0 {
+            return delay;
+        }
+        half + Duration::from_nanos(random_u64() % (nanos + 1))
+    }
+}
+
+fn random_u64() -> u64 {
+    RandomState::new().build_hasher().finish()
+}
@@ -0,0 +1,394 @@
+//! Targeting search: looks up the IDs and keys that [`Targeting`](crate::types::Targeting) entries need.
+//!
+//! See <https://developers.facebook.com/docs/marketing-api/audiences/reference/targeting-search>.
+
+use crate::client::{to_json, FacebookClient, GraphRequest};
+use crate::errors::FacebookError;
+use crate::types::{CityTargeting, FlexibleTargeting, GeoLocations, InterestOrBehavior, ListResponse, LocationKey};
+use serde::de::DeserializeOwned;
+use serde::Deserialize;
+
+graph_enum! {
+    /// Kind of location searched with [`GeoLocationSearch`].
+    pub enum GeoLocationType {
+        Country => "country",
+        CountryGroup => "country_group",
+        Region => "region",
+        City => "city",
+        Zip => "zip",
+        GeoMarket => "geo_market",
+        ElectoralDistrict => "electoral_district",
+    }
+}
+
+graph_enum! {
+    /// Class of targeting categories browsed with `adTargetingCategory`.
+    pub enum TargetingCategoryClass {
+        Interests => "interests",
+        Behaviors => "behaviors",
+        Demographics => "demographics",
+        LifeEvents => "life_events",
+        Industries => "industries",
+        Income => "income",
+        FamilyStatuses => "family_statuses",
+        UserDevice => "user_device",
+        UserOs => "user_os",
+    }
+}
+
+/// An interest found by `adinterest` or suggested by `adinterestsuggestion`.
+#[derive(Deserialize, Debug, Clone)]
+pub struct InterestResult {
+    pub id: String,
+    pub name: String,
+    pub audience_size_lower_bound: Option<i64>,
+    pub audience_size_upper_bound: Option<i64>,
+    pub path: Option<Vec<String>>,
+    pub topic: Option<String>,
+    pub description: Option<String>,
+    pub disambiguation_category: Option<String>,
+}
+
+impl From<InterestResult> for InterestOrBehavior {
+    fn from(interest: InterestResult) -> Self {
+        InterestOrBehavior {
+            id: interest.id,
+            name: Some(interest.name),
+        }
+    }
+}
+
+impl From<&InterestResult> for InterestOrBehavior {
+    fn from(interest: &InterestResult) -> Self {
+        interest.clone().into()
+    }
+}
+
+/// Whether an interest can still be targeted, from `adinterestvalid`.
+#[derive(Deserialize, Debug, Clone)]
+pub struct InterestValidity {
+    pub id: Option<String>,
+    pub name: Option<String>,
+    pub valid: bool,
+    pub audience_size_lower_bound: Option<i64>,
+    pub audience_size_upper_bound: Option<i64>,
+}
+
+impl InterestValidity {
+    /// The interest as a targeting entry, if it is valid.
+    pub fn to_interest(&self) -> Option<InterestOrBehavior> {
+        match (&self.id, self.valid) {
+            (Some(id), true) => Some(InterestOrBehavior {
+                id: id.clone(),
+                name: self.name.clone(),
+            }),
+            _ => None,
+        }
+    }
+}
+
+/// Parameters of an `adgeolocation` search.
+#[derive(Debug, Clone)]
+pub struct GeoLocationSearch {
+    pub query: String,
+    pub location_types: Vec<GeoLocationType>,
+    /// Limits regions, cities and zips to one country.
+    pub country_code: Option<String>,
+    pub limit: Option<u32>,
+}
+
+impl GeoLocationSearch {
+    /// Searches every kind of location by name.
+    pub fn new(query: &str) -> Self {
+        GeoLocationSearch {
+            query: query.to_string(),
+            location_types: Vec::new(),
+            country_code: None,
+            limit: None,
+        }
+    }
+
+    pub fn location_types(mut self, location_types: &[GeoLocationType]) -> Self {
+        self.location_types = location_types.to_vec();
+        self
+    }
+
+    pub fn country_code(mut self, country_code: &str) -> Self {
+        self.country_code = Some(country_code.to_uppercase());
+        self
+    }
+
+    pub fn limit(mut self, limit: u32) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    fn apply(&self, mut request: GraphRequest) -> GraphRequest {
+        request = request.query("q", self.query.as_str());
+        if !self.location_types.is_empty() {
+            request = request.query("location_types", to_json(&self.location_types));
+        }
+        if let Some(country_code) = &self.country_code {
+            request = request.query("country_code", country_code.as_str());
+        }
+        if let Some(limit) = self.limit {
+            request = request.query("limit", limit.to_string());
+        }
+        request
+    }
+}
+
+/// A location found by `adgeolocation`.
+#[derive(Deserialize, Debug, Clone)]
+pub struct GeoLocationResult {
+    pub key: String,
+    pub name: String,
+    /// `country`, `region`, `city`, `zip`, ...; see [`Self::location_type`].
+    #[serde(rename = "type")]
+    pub location_type_name: String,
+    pub country_code: Option<String>,
+    pub country_name: Option<String>,
+    pub region: Option<String>,
+    pub supports_region: Option<bool>,
+    pub supports_city: Option<bool>,
+}
+
+impl GeoLocationResult {
+    /// The kind of location, when it is one this crate models.
+    pub fn location_type(&self) -> Option<GeoLocationType> {
+        self.location_type_name.parse().ok()
+    }
+
+    /// Adds the location to the list of `geo` that matches its type.
+    ///
+    /// Fails for kinds of location [`GeoLocations`] does not hold.
+    pub fn add_to(&self, geo: &mut GeoLocations) -> Result<(), FacebookError> {
+        let location_key = || LocationKey {
+            key: self.key.clone(),
+            name: Some(self.name.clone()),
+            country: self.country_code.clone(),
+        };
+        match self.location_type() {
+            Some(GeoLocationType::Country) => geo.countries.get_or_insert_with(Vec::new).push(self.key.clone()),
+            Some(GeoLocationType::CountryGroup) => {
+                geo.country_groups.get_or_insert_with(Vec::new).push(self.key.clone())
+            }
+            Some(GeoLocationType::Region) => geo.regions.get_or_insert_with(Vec::new).push(location_key()),
+            Some(GeoLocationType::City) => geo.cities.get_or_insert_with(Vec::new).push(CityTargeting {
+                key: self.key.clone(),
+                name: Some(self.name.clone()),
+                region: self.region.clone(),
+                country: self.country_code.clone(),
+                radius: None,
+                distance_unit: None,
+            }),
+            Some(GeoLocationType::Zip) => geo.zips.get_or_insert_with(Vec::new).push(location_key()),
+            Some(GeoLocationType::GeoMarket) => geo.geo_markets.get_or_insert_with(Vec::new).push(location_key()),
+            Some(GeoLocationType::ElectoralDistrict) => {
+                geo.electoral_districts.get_or_insert_with(Vec::new).push(location_key())
+            }
+            None => {
+                return Err(FacebookError::invalid(&format!(
+                    "locations of type {} cannot be added to geo_locations",
+                    self.location_type_name
+                )))
+            }
+        }
+        Ok(())
+    }
+}
+
+impl From<GeoLocationResult> for LocationKey {
+    fn from(location: GeoLocationResult) -> Self {
+        LocationKey {
+            key: location.key,
+            name: Some(location.name),
+            country: location.country_code,
+        }
+    }
+}
+
+/// A behavior, demographic or other category listed by `adTargetingCategory`.
+#[derive(Deserialize, Debug, Clone)]
+pub struct TargetingCategory {
+    /// Missing for `user_device` and `user_os` entries, which are targeted by name.
+    pub id: Option<String>,
+    pub name: String,
+    /// The `Targeting` key the category belongs to: `behaviors`, `life_events`, `industries`, ...
+    #[serde(rename = "type")]
+    pub category_type: Option<String>,
+    pub path: Option<Vec<String>>,
+    pub description: Option<String>,
+    pub audience_size_lower_bound: Option<i64>,
+    pub audience_size_upper_bound: Option<i64>,
+}
+
+impl TargetingCategory {
+    /// Adds the category to the list of `spec` named by its type.
+    ///
+    /// Fails for types [`FlexibleTargeting`] does not hold and for categories without an ID.
+    pub fn add_to(&self, spec: &mut FlexibleTargeting) -> Result<(), FacebookError> {
+        let category_type = self.category_type.as_deref().unwrap_or_default();
+        let id = self.require_id()?;
+        let entries = match category_type {
+            "interests" => &mut spec.interests,
+            "behaviors" => &mut spec.behaviors,
+            "life_events" => &mut spec.life_events,
+            "industries" => &mut spec.industries,
+            "income" => &mut spec.income,
+            "family_statuses" => &mut spec.family_statuses,
+            "work_positions" => &mut spec.work_positions,
+            "work_employers" => &mut spec.work_employers,
+            "education_schools" => &mut spec.education_schools,
+            "education_majors" => &mut spec.education_majors,
+            "education_statuses" | "relationship_statuses" => {
+                let id = id
+                    .parse()
+                    .map_err(|_| FacebookError::invalid(&format!("{} ID {} is not a number", category_type, id)))?;
+                let statuses = if category_type == "education_statuses" {
+                    &mut spec.education_statuses
+                } else {
+                    &mut spec.relationship_statuses
+                };
+                statuses.get_or_insert_with(Vec::new).push(id);
+                return Ok(());
+            }
+            _ => {
+                return Err(FacebookError::invalid(&format!(
+                    "categories of type {:?} cannot be added to a flexible_spec",
+                    category_type
+                )))
+            }
+        };
+        entries.get_or_insert_with(Vec::new).push(InterestOrBehavior {
+            id: id.to_string(),
+            name: Some(self.name.clone()),
+        });
+        Ok(())
+    }
+
+    fn require_id(&self) -> Result<&str, FacebookError> {
+        self.id
+            .as_deref()
+            .ok_or_else(|| FacebookError::invalid(&format!("category {} has no ID to target it by", self.name)))
+    }
+}
+
+impl TryFrom<TargetingCategory> for InterestOrBehavior {
+    type Error = FacebookError;
+
+    /// Fails for categories without an ID, such as `user_device` and `user_os` entries.
+    fn try_from(category: TargetingCategory) -> Result<Self, Self::Error> {
+        (&category).try_into()
+    }
+}
+
+impl TryFrom<&TargetingCategory> for InterestOrBehavior {
+    type Error = FacebookError;
+
+    fn try_from(category: &TargetingCategory) -> Result<Self, Self::Error> {
+        Ok(InterestOrBehavior {
+            id: category.require_id()?.to_string(),
+            name: Some(category.name.clone()),
+        })
+    }
+}
+
+/// A locale found by `adlocale`. Its `key` goes into `Targeting::locales`.
+#[derive(Deserialize, Debug, Clone)]
+pub struct LocaleResult {
+    pub key: u32,
+    pub name: String,
+}
+
+impl FacebookClient {
+    /// Searches interests by name.
+    pub async fn search_interests(&self, query: &str, limit: Option<u32>) -> Result<Vec<InterestResult>, FacebookError> {
+        self.search(Self::search_interests_request(query, limit)).await
+    }
+
+    pub(crate) fn search_interests_request(query: &str, limit: Option<u32>) -> GraphRequest {
+        with_limit(search_request("adinterest").query("q", query), limit)
+    }
+
+    /// Suggests interests related to the given interest names.
+    pub async fn suggest_interests(
+        &self,
+        interest_names: &[&str],
+        limit: Option<u32>,
+    ) -> Result<Vec<InterestResult>, FacebookError> {
+        self.search(Self::suggest_interests_request(interest_names, limit)).await
+    }
+
+    pub(crate) fn suggest_interests_request(interest_names: &[&str], limit: Option<u32>) -> GraphRequest {
+        with_limit(
+            search_request("adinterestsuggestion").query("interest_list", to_json(&interest_names)),
+            limit,
+        )
+    }
+
+    /// Checks whether interests, given by name, can still be targeted.
+    pub async fn validate_interests(&self, interest_names: &[&str]) -> Result<Vec<InterestValidity>, FacebookError> {
+        self.search(Self::validate_interests_request("interest_list", interest_names))
+            .await
+    }
+
+    /// Checks whether interests, given by ID, can still be targeted.
+    pub async fn validate_interest_ids(&self, interest_ids: &[&str]) -> Result<Vec<InterestValidity>, FacebookError> {
+        self.search(Self::validate_interests_request("interest_fbid_list", interest_ids))
+            .await
+    }
+
+    pub(crate) fn validate_interests_request(key: &str, interests: &[&str]) -> GraphRequest {
+        search_request("adinterestvalid").query(key, to_json(&interests))
+    }
+
+    /// Searches countries, regions, cities, zips and other locations by name.
+    pub async fn search_geolocations(
+        &self,
+        search: &GeoLocationSearch,
+    ) -> Result<Vec<GeoLocationResult>, FacebookError> {
+        self.search(Self::search_geolocations_request(search)).await
+    }
+
+    pub(crate) fn search_geolocations_request(search: &GeoLocationSearch) -> GraphRequest {
+        search.apply(search_request("adgeolocation"))
+    }
+
+    /// Lists the targeting categories of a class, e.g. every behavior.
+    pub async fn search_targeting_categories(
+        &self,
+        class: TargetingCategoryClass,
+    ) -> Result<Vec<TargetingCategory>, FacebookError> {
+        self.search(Self::search_targeting_categories_request(class)).await
+    }
+
+    pub(crate) fn search_targeting_categories_request(class: TargetingCategoryClass) -> GraphRequest {
+        search_request("adTargetingCategory").query("class", class.as_str())
+    }
+
+    /// Searches locales by name.
+    pub async fn search_locales(&self, query: &str, limit: Option<u32>) -> Result<Vec<LocaleResult>, FacebookError> {
+        self.search(Self::search_locales_request(query, limit)).await
+    }
+
+    pub(crate) fn search_locales_request(query: &str, limit: Option<u32>) -> GraphRequest {
+        with_limit(search_request("adlocale").query("q", query), limit)
+    }
+
+    async fn search<T: DeserializeOwned>(&self, request: GraphRequest) -> Result<Vec<T>, FacebookError> {
+        let response: ListResponse<T> = self.execute(request).await?;
+        Ok(response.data)
+    }
+}
+
+fn search_request(search_type: &str) -> GraphRequest {
+    GraphRequest::get("search").query("type", search_type)
+}
+
+fn with_limit(request: GraphRequest, limit: Option<u32>) -> GraphRequest {
+    match limit {
+        Some(limit) => request.query("limit", limit.to_string()),
+        None => request,
+    }
+}
@@ -0,0 +1,69 @@
+//! Redaction of credentials.
+
+use serde::Deserialize;
+use std::fmt;
+
+/// Query and form parameters whose values are credentials.
+const SECRET_PARAMS: &[&str] = &[
+    "access_token",
+    "client_secret",
+    "input_token",
+    "fb_exchange_token",
+];
+
+const REDACTED: &str = "[REDACTED]";
+
+/// A credential (app secret, access token) that never shows up in `Debug` output.
+///
+/// The value is only reachable through [`Secret::expose`], which makes every
+/// place that handles the raw credential easy to find.
+#[derive(Clone, PartialEq, Eq, Deserialize)]
+#[serde(transparent)]
+pub struct Secret(String);
+
+impl Secret {
+    pub fn new(value: impl Into<String>) -> Self {
+        Secret(value.into())
+    }
+
+    pub fn expose(&self) -> &str {
+        &self.0
+    }
+}
+
+impl fmt::Debug for Secret {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Secret({})", REDACTED)
+    }
+}
+
+impl From<&str> for Secret {
+    fn from(value: &str) -> Self {
+        Secret::new(value)
+    }
+}
+
+impl From<String> for Secret {
+    fn from(value: String) -> Self {
+        Secret::new(value)
+    }
+}
+
+/// Replaces the values of credential parameters (`access_token=...`, `client_secret=...`)
+/// in URLs, query strings and form bodies embedded in `text`.
+pub fn scrub_secrets(text: &str) -> String {
+    let mut scrubbed = text.to_string();
+    for param in SECRET_PARAMS {
+        let needle = format!("{}=", param);
+        let mut from = 0;
+        while let Some(found) = scrubbed[from..].find(&needle) {
+            let value_start = from + found + needle.len();
+            let value_end = scrubbed[value_start..]
+                .find(|c: char| c == '&' || c == '"' || c == '\'' || c == ')' || c.is_whitespace())
+                .map_or(scrubbed.len(), |end| value_start + end);
+            scrubbed.replace_range(value_start..value_end, REDACTED);
+            from = value_start + REDACTED.len();
+        }
+    }
+    scrubbed
+}
@@ -0,0 +1,217 @@
+//! Access token lifecycle: long-lived exchange, inspection and expiry detection.
+
+use crate::client::{FacebookClient, GraphRequest, TokenKind};
+use crate::errors::{FacebookError, GraphApiError};
+use crate::secret::Secret;
+use serde::Deserialize;
+use std::fmt;
+use std::sync::Arc;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// Graph subcode of error 190 sent when the token has expired.
+const SUBCODE_TOKEN_EXPIRED: i64 = 463;
+
+/// Response of `oauth/access_token`.
+#[derive(Deserialize, Debug, Clone)]
+pub struct AccessTokenResponse {
+    pub access_token: Secret,
+    pub token_type: Option<String>,
+    /// Seconds until the token expires. Absent for tokens that do not expire.
+    pub expires_in: Option<u64>,
+}
+
+/// The `data` object returned by `/debug_token`.
+#[derive(Deserialize, Debug, Clone)]
+pub struct DebugTokenData {
+    pub app_id: Option<String>,
+    #[serde(rename = "type")]
+    pub token_type: Option<String>,
+    pub application: Option<String>,
+    /// Unix time at which data access expires.
+    pub data_access_expires_at: Option<i64>,
+    /// Unix time at which the token expires; `0` means it never does.
+    pub expires_at: Option<i64>,
+    pub is_valid: bool,
+    pub issued_at: Option<i64>,
+    #[serde(default)]
+    pub scopes: Vec<String>,
+    #[serde(default)]
+    pub granular_scopes: Vec<GranularScope>,
+    pub user_id: Option<String>,
+    pub error: Option<DebugTokenError>,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct GranularScope {
+    pub scope: String,
+    /// IDs the scope is restricted to, such as ad account or page IDs. Absent when it applies to all.
+    pub target_ids: Option<Vec<String>>,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct DebugTokenError {
+    pub code: Option<i64>,
+    pub message: Option<String>,
+    pub subcode: Option<i64>,
+}
+
+#[derive(Deserialize, Debug)]
+struct DebugTokenResponse {
+    data: DebugTokenData,
+}
+
+impl DebugTokenData {
+    /// Time left before the token expires, or `None` if it does not expire.
+    pub fn expires_in(&self) -> Option<Duration> {
+        let expires_at = self.expires_at.filter(|expires_at| *expires_at > 0)?;
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |now| now.as_secs() as i64);
+        Some(Duration::from_secs(expires_at.saturating_sub(now).max(0) as u64))
+    }
+
+    pub fn has_scope(&self, scope: &str) -> bool {
+        self.scopes.iter().any(|granted| granted == scope)
+    }
+
+    /// The IDs a scope is restricted to, e.g. the ad accounts of `ads_management`.
+    pub fn scope_targets(&self, scope: &str) -> Option<&[String]> {
+        self.granular_scopes
+            .iter()
+            .find(|granular| granular.scope == scope)
+            .and_then(|granular| granular.target_ids.as_deref())
+    }
+}
+
+/// State of an access token, as observed by the client.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TokenEvent {
+    /// The token is valid. `expires_in` is `None` for tokens that never expire.
+    Valid { expires_in: Option<Duration> },
+    /// The token is valid but expires within the configured warning window.
+    ExpiringSoon { expires_in: Duration },
+    /// The token has expired.
+    Expired,
+    /// The token was revoked or is otherwise unusable.
+    Invalid { message: String },
+}
+
+impl TokenEvent {
+    /// Whether the token still authenticates calls.
+    pub fn is_usable(&self) -> bool {
+        matches!(self, TokenEvent::Valid { .. } | TokenEvent::ExpiringSoon { .. })
+    }
+
+    fn from_debug(data: &DebugTokenData, warn_within: Duration) -> Self {
+        if !data.is_valid {
+            let error = data.error.as_ref();
+            if error.and_then(|error| error.subcode) == Some(SUBCODE_TOKEN_EXPIRED) {
+                return TokenEvent::Expired;
+            }
+            let message = error
+                .and_then(|error| error.message.clone())
+                .unwrap_or_else(|| "token is not valid".to_string());
+            return TokenEvent::Invalid { message };
+        }
+        match data.expires_in() {
+            Some(expires_in) if expires_in.is_zero() => TokenEvent::Expired,
+            Some(expires_in) if expires_in <= warn_within => TokenEvent::ExpiringSoon { expires_in },
+            expires_in => TokenEvent::Valid { expires_in },
+        }
+    }
+
+    /// The event a failed call signals, if it failed because of its token (code 190).
+    pub(crate) fn from_graph_error(error: &GraphApiError) -> Option<Self> {
+        if !error.is_token_expired() {
+            return None;
+        }
+        if error.error_subcode == Some(SUBCODE_TOKEN_EXPIRED) {
+            Some(TokenEvent::Expired)
+        } else {
+            Some(TokenEvent::Invalid {
+                message: error.message.clone(),
+            })
+        }
+    }
+}
+
+/// Callback notified of [`TokenEvent`]s.
+#[derive(Clone)]
+pub struct TokenEventHandler(Arc<dyn Fn(&TokenEvent) + Send + Sync>);
+
+impl TokenEventHandler {
+    pub fn new(handler: impl Fn(&TokenEvent) + Send + Sync + 'static) -> Self {
+        TokenEventHandler(Arc::new(handler))
+    }
+
+    pub(crate) fn notify(&self, event: &TokenEvent) {
+        (self.0)(event)
+    }
+}
+
+impl fmt::Debug for TokenEventHandler {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("TokenEventHandler")
+    }
+}
+
+impl FacebookClient {
+    /// Exchanges a short-lived user token for a long-lived one (about 60 days).
+    ///
+    /// The client's own token is left unchanged; see [`FacebookClient::set_user_token`].
+    pub async fn exchange_token(&self, short_lived_token: &str) -> Result<AccessTokenResponse, FacebookError> {
+        let request = GraphRequest::post("oauth/access_token")
+            .token(TokenKind::None)
+            .idempotent(true)
+            .form("grant_type", "fb_exchange_token")
+            .form("client_id", self.app_id.as_str())
+            .form("client_secret", self.app_secret.expose())
+            .form("fb_exchange_token", short_lived_token);
+        self.execute(request).await
+    }
+
+    /// Replaces the client's user token with a long-lived exchange of it.
+    pub async fn upgrade_to_long_lived_token(&mut self) -> Result<AccessTokenResponse, FacebookError> {
+        let response = self.exchange_token(self.user_token.expose()).await?;
+        self.set_user_token(response.access_token.clone());
+        Ok(response)
+    }
+
+    /// Replaces the user token sent with every call.
+    pub fn set_user_token(&mut self, user_token: impl Into<Secret>) {
+        self.user_token = user_token.into();
+    }
+
+    /// Inspects a token with `/debug_token`: validity, expiry, scopes and granular scopes.
+    ///
+    /// The call is authenticated with the App Access Token.
+    pub async fn debug_token(&self, input_token: &str) -> Result<DebugTokenData, FacebookError> {
+        let request = GraphRequest::get("debug_token")
+            .token(TokenKind::App)
+            .query("input_token", input_token);
+        let response: DebugTokenResponse = self.execute(request).await?;
+        Ok(response.data)
+    }
+
+    /// Checks the client's user token, reporting `ExpiringSoon` when it expires within `warn_within`.
+    ///
+    /// The resulting event is also passed to the token event handler, if one is set.
+    pub async fn check_user_token(&self, warn_within: Duration) -> Result<TokenEvent, FacebookError> {
+        let data = self.debug_token(self.user_token.expose()).await?;
+        let event = TokenEvent::from_debug(&data, warn_within);
+        self.notify_token_event(&event);
+        Ok(event)
+    }
+
+    /// Calls `handler` whenever a token check runs or a call fails because of its token.
+    pub fn with_token_event_handler(mut self, handler: impl Fn(&TokenEvent) + Send + Sync + 'static) -> Self {
+        self.token_event_handler = Some(TokenEventHandler::new(handler));
+        self
+    }
+
+    pub(crate) fn notify_token_event(&self, event: &TokenEvent) {
+        if let Some(handler) = &self.token_event_handler {
+            handler.notify(event);
+        }
+    }
+}
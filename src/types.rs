@@ -1,17 +1,41 @@
-use serde::Deserialize;
+use crate::campaign::{BidStrategy, ConfiguredStatus, Objective};
+use crate::secret::Secret;
+use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
 #[derive(Deserialize, Debug)]
 pub struct AppAccessResponse {
-    pub access_token: String,
+    pub access_token: Secret,
 }
 
+/// Body returned by Graph when an object is created.
 #[derive(Deserialize, Debug)]
-pub struct AdsAccountsResponse {
-    pub data: Vec<AdAccount>,
-    pub paging: Paging,
+pub struct CreatedObject {
+    pub id: String,
 }
 
+/// Body returned by Graph for status updates and deletions.
+#[derive(Deserialize, Debug)]
+pub struct SuccessResponse {
+    pub success: bool,
+}
+
+/// One page of a Graph list edge (`{"data": [...], "paging": {...}}`).
+#[derive(Deserialize, Debug)]
+pub struct ListResponse<T> {
+    pub data: Vec<T>,
+    pub paging: Option<Paging>,
+}
+
+impl<T> ListResponse<T> {
+    /// URL of the next page, if Graph reported one.
+    pub fn next_page_url(&self) -> Option<&str> {
+        self.paging.as_ref().and_then(|paging| paging.next.as_deref())
+    }
+}
+
+pub type AdsAccountsResponse = ListResponse<AdAccount>;
+
 #[derive(Deserialize, Debug)]
 pub struct AdAccount {
     pub account_id: String,
@@ -20,13 +44,34 @@ pub struct AdAccount {
 
 #[derive(Deserialize, Debug)]
 pub struct Paging {
-    pub cursors: Cursors,
+    pub cursors: Option<Cursors>,
+    pub next: Option<String>,
+    pub previous: Option<String>,
 }
 
 #[derive(Deserialize, Debug)]
 pub struct Cursors {
-    pub before: String,
-    pub after: String,
+    pub before: Option<String>,
+    pub after: Option<String>,
+}
+
+impl Paging {
+    pub fn display(&self) {
+        if let Some(cursors) = &self.cursors {
+            if let Some(before) = &cursors.before {
+                println!("Before Cursor: {}", before);
+            }
+            if let Some(after) = &cursors.after {
+                println!("After Cursor: {}", after);
+            }
+        }
+        if let Some(next) = &self.next {
+            println!("Next Page: {}", next);
+        }
+        if let Some(previous) = &self.previous {
+            println!("Previous Page: {}", previous);
+        }
+    }
 }
 
 impl AdsAccountsResponse {
@@ -37,24 +82,21 @@ impl AdsAccountsResponse {
             println!("Ad Account ID: {}", account.account_id);
             println!("Ad Account Internal ID: {}", account.id);
         }
-        println!("\nPaging Information:");
-        println!("Before Cursor: {}", self.paging.cursors.before);
-        println!("After Cursor: {}", self.paging.cursors.after);
+        if let Some(paging) = &self.paging {
+            println!("\nPaging Information:");
+            paging.display();
+        }
     }
 }
 
-#[derive(Deserialize, Debug)]
-pub struct CampaignsResponse {
-    pub data: Vec<Campaign>,
-    pub paging: Paging,
-}
+pub type CampaignsResponse = ListResponse<Campaign>;
 
 #[derive(Deserialize, Debug)]
 pub struct Campaign {
     pub id: Option<String>,                                // Campaign's ID
     pub account_id: Option<String>, // ID of the ad account that owns this campaign
     pub adlabels: Option<Vec<AdLabel>>, // List of Ad Labels associated with this campaign
-    pub bid_strategy: Option<String>, // Bid strategy
+    pub bid_strategy: Option<BidStrategy>, // Bid strategy
     pub boosted_object_id: Option<String>, // The Boosted Object this campaign has associated, if any
     pub brand_lift_studies: Option<Vec<AdStudy>>, // Automated Brand Lift V2 studies for this ad set
     pub budget_rebalance_flag: Option<bool>, // Whether to automatically rebalance budgets daily
@@ -63,7 +105,7 @@ pub struct Campaign {
     pub campaign_group_active_time: Option<String>, // Internal campaign group active time
     pub can_create_brand_lift_study: Option<bool>, // If we can create a new brand lift study
     pub can_use_spend_cap: Option<bool>,   // Whether the campaign can set the spend cap
-    pub configured_status: Option<String>, // Campaign status (ACTIVE, PAUSED, etc.)
+    pub configured_status: Option<ConfiguredStatus>, // Campaign status (ACTIVE, PAUSED, etc.)
     pub created_time: Option<String>,      // Created time
     pub daily_budget: Option<String>,      // Daily budget
     pub effective_status: Option<String>,  // Effective status (ACTIVE, PAUSED, etc.)
@@ -74,7 +116,7 @@ pub struct Campaign {
     pub last_budget_toggling_time: Option<String>, // Last budget toggling time
     pub lifetime_budget: Option<String>,          // Lifetime budget
     pub name: Option<String>,                     // Campaign's name
-    pub objective: Option<String>,                // Campaign's objective
+    pub objective: Option<Objective>,             // Campaign's objective
     pub pacing_type: Option<Vec<String>>,         // Defines pacing type (e.g., "standard")
     pub primary_attribution: Option<String>,      // Primary attribution
     pub promoted_object: Option<AdPromotedObject>, // The object this campaign is promoting
@@ -86,7 +128,7 @@ pub struct Campaign {
     pub special_ad_category_country: Option<Vec<String>>, // Countries for special ad category
     pub spend_cap: Option<String>,           // Spend cap for the campaign
     pub start_time: Option<String>,          // Start time
-    pub status: Option<String>,              // Status (ACTIVE, PAUSED, etc.)
+    pub status: Option<ConfiguredStatus>,    // Status (ACTIVE, PAUSED, etc.)
     pub stop_time: Option<String>,           // Stop time
     pub topline_id: Option<String>,          // Topline ID
     pub updated_time: Option<String>,        // Updated time
@@ -127,41 +169,63 @@ pub struct AdCampaignIssuesInfo {
     pub level: Option<String>,
 }
 
-#[derive(Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
 pub struct AdPromotedObject {
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub application_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub boosted_product_set_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub conversion_goal_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub custom_conversion_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub custom_event_str: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub custom_event_type: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub event_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub lead_ads_custom_event_str: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub lead_ads_custom_event_type: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub lead_ads_form_event_source_type: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub mcme_conversion_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub object_store_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub offer_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub offline_conversion_data_set_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub offsite_conversion_event_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub page_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub pixel_aggregation_rule: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub pixel_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub pixel_rule: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub place_page_set_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub product_catalog_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub product_set_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub retention_days: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub value_semantic_type: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub variation: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub whatsapp_phone_number: Option<String>,
 }
 
-#[derive(Deserialize, Debug)]
-pub struct AdSetResponse {
-    pub data: Vec<AdSet>,
-    pub paging: Paging,
-}
+pub type AdSetResponse = ListResponse<AdSet>;
 
 #[derive(Deserialize, Debug)]
 pub struct AdSet {
@@ -229,12 +293,16 @@ pub struct AdSet {
     pub use_new_app_click: Option<bool>,
 }
 
-#[derive(Debug, Clone, Deserialize)]
+/// One block of an ad set's `adset_schedule`. Minutes count from midnight; days run from 0 (Sunday) to 6.
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct DayPart {
     pub start_minute: i32,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub end_minute: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub days: Option<Vec<i32>>,
-    pub timezone_type: Option<String>, // Optional: "user" or "advertizer"
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timezone_type: Option<String>, // Optional: "USER" or "ADVERTISER"
 }
 
 #[derive(Debug, Clone, Deserialize)]
@@ -246,10 +314,13 @@ pub struct AdBidAdjustments {
 #[derive(Debug, Clone, Deserialize)]
 pub struct AdCampaignBidConstraint;
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AdCampaignFrequencyControlSpecs {
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub event: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub interval_days: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub max_frequency: Option<u32>,
 }
 #[derive(Debug, Deserialize)]
@@ -265,58 +336,227 @@ pub struct AdRecommendation {
     pub r#type: String,
     pub object_ids: Option<Vec<String>>,
 }
-#[derive(Debug, Deserialize)]
+/// Who an ad set is delivered to, in the shape Graph reads and writes.
+///
+/// Keys this struct does not model are kept in `extra`, so a targeting read from Graph
+/// can be written back unchanged.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct Targeting {
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub genders: Option<Vec<u8>>, // 1 = males, 2 = females
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub age_min: Option<u8>, // Min 13, default 18
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub age_max: Option<u8>,
-    pub countries: Option<Vec<String>>, 
-    pub regions: Option<Vec<LocationKey>>, 
-    pub cities: Option<Vec<CityTargeting>>, 
-    pub zips: Option<Vec<LocationKey>>, 
-    pub places: Option<Vec<PlaceTargeting>>, 
-    pub custom_locations: Option<Vec<CustomLocation>>, 
-    pub geo_markets: Option<Vec<LocationKey>>, 
-    pub electoral_districts: Option<Vec<LocationKey>>, 
-    pub location_types: Option<Vec<String>>, 
-    pub country_groups: Option<Vec<String>>, 
-    pub interests: Option<Vec<InterestOrBehavior>>, 
-    pub behaviors: Option<Vec<InterestOrBehavior>>, 
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub geo_locations: Option<GeoLocations>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub excluded_geo_locations: Option<GeoLocations>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub locales: Option<Vec<u32>>, // Locale keys from the `adlocale` search
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub interests: Option<Vec<InterestOrBehavior>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub behaviors: Option<Vec<InterestOrBehavior>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub education_statuses: Option<Vec<u32>>, // 1 = high school, 2 = undergrad, 3 = alum, ...
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub relationship_statuses: Option<Vec<u32>>, // 1 = single, 2 = in a relationship, 3 = married, ...
+    /// Groups ANDed together; the entries within one group are ORed.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub flexible_spec: Option<Vec<FlexibleTargeting>>,
+    /// People matching any of these entries are left out.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub exclusions: Option<FlexibleTargeting>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_audiences: Option<Vec<AudienceRef>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub excluded_custom_audiences: Option<Vec<AudienceRef>>,
+    /// Placements: unset means Advantage+ placements.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub publisher_platforms: Option<Vec<PublisherPlatform>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub facebook_positions: Option<Vec<String>>, // "feed", "story", "marketplace", "facebook_reels", ...
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub instagram_positions: Option<Vec<String>>, // "stream", "story", "explore", "reels", ...
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub audience_network_positions: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub messenger_positions: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub device_platforms: Option<Vec<DevicePlatform>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub targeting_automation: Option<TargetingAutomation>,
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
 }
 
-#[derive(Debug, Deserialize)]
+/// One group of a `flexible_spec`, or the `exclusions` of a [`Targeting`].
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct FlexibleTargeting {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub interests: Option<Vec<InterestOrBehavior>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub behaviors: Option<Vec<InterestOrBehavior>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub life_events: Option<Vec<InterestOrBehavior>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub industries: Option<Vec<InterestOrBehavior>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub income: Option<Vec<InterestOrBehavior>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub family_statuses: Option<Vec<InterestOrBehavior>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub work_positions: Option<Vec<InterestOrBehavior>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub work_employers: Option<Vec<InterestOrBehavior>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub education_schools: Option<Vec<InterestOrBehavior>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub education_majors: Option<Vec<InterestOrBehavior>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub education_statuses: Option<Vec<u32>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub relationship_statuses: Option<Vec<u32>>,
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
+}
+
+/// A custom or lookalike audience included in or excluded from a [`Targeting`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AudienceRef {
+    pub id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+}
+
+graph_enum! {
+    pub enum PublisherPlatform {
+        Facebook => "facebook",
+        Instagram => "instagram",
+        AudienceNetwork => "audience_network",
+        Messenger => "messenger",
+        Threads => "threads",
+        _ => Other(String),
+    }
+}
+
+graph_enum! {
+    pub enum DevicePlatform {
+        Mobile => "mobile",
+        Desktop => "desktop",
+        _ => Other(String),
+    }
+}
+
+/// Advantage+ audience settings of a [`Targeting`].
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct TargetingAutomation {
+    /// `1` lets Graph reach beyond the targeting, which then acts as a suggestion; `0` keeps it strict.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub advantage_audience: Option<u8>,
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
+}
+
+/// Locations of a [`Targeting`]. Graph requires at least one of them when an ad set is created.
+///
+/// Location kinds this struct does not model are kept in `extra`, like [`Targeting`]'s.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct GeoLocations {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub countries: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub regions: Option<Vec<LocationKey>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cities: Option<Vec<CityTargeting>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub zips: Option<Vec<LocationKey>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub places: Option<Vec<PlaceTargeting>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_locations: Option<Vec<CustomLocation>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub geo_markets: Option<Vec<LocationKey>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub electoral_districts: Option<Vec<LocationKey>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub location_types: Option<Vec<String>>, // "home", "recent"
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub country_groups: Option<Vec<String>>,
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
+}
+
+impl GeoLocations {
+    pub fn is_empty(&self) -> bool {
+        self.countries.as_ref().is_none_or(Vec::is_empty)
+            && self.regions.as_ref().is_none_or(Vec::is_empty)
+            && self.cities.as_ref().is_none_or(Vec::is_empty)
+            && self.zips.as_ref().is_none_or(Vec::is_empty)
+            && self.places.as_ref().is_none_or(Vec::is_empty)
+            && self.custom_locations.as_ref().is_none_or(Vec::is_empty)
+            && self.geo_markets.as_ref().is_none_or(Vec::is_empty)
+            && self.electoral_districts.as_ref().is_none_or(Vec::is_empty)
+            && self.country_groups.as_ref().is_none_or(Vec::is_empty)
+            && self.extra.is_empty()
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct LocationKey {
     pub key: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub country: Option<String>,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CityTargeting {
-    pub key: String, 
-    pub radius: u8, 
-    pub distance_unit: String,
+    pub key: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub region: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub country: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub radius: Option<u8>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub distance_unit: Option<String>,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PlaceTargeting {
-    pub key: String, 
-    pub name: String, 
-    pub radius: u8, 
-    pub distance_unit: String, 
+    pub key: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub radius: Option<u8>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub distance_unit: Option<String>,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CustomLocation {
-    pub latitude: f64, 
-    pub longitude: f64, 
-    pub name: Option<String>, 
-    pub radius: f64, 
-    pub distance_unit: Option<String>, 
-    pub address_string: Option<String>, 
+    pub latitude: f64,
+    pub longitude: f64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    pub radius: f64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub distance_unit: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub address_string: Option<String>,
 }
 
-#[derive(Debug, Deserialize)]
+/// An interest, behavior or other targeting entity (life event, industry, employer, ...).
+/// Graph returns the ID as a string.
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct InterestOrBehavior {
-    pub id: u64, 
+    pub id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub name: Option<String>,
 }
 #[derive(Debug, Deserialize)]
@@ -325,11 +565,7 @@ pub struct TargetingOptimizationTypes {
     pub value : Option<i64>,
 }
 
-#[derive(Deserialize, Debug)]
-pub struct AdResponse {
-    pub data: Vec<AdSet>,
-    pub paging: Paging,
-}
+pub type AdResponse = ListResponse<Ad>;
 
 #[derive(Debug,Deserialize)]
 pub struct Ad {
@@ -348,7 +584,7 @@ pub struct Ad {
     pub configured_status: Option<String>, // {ACTIVE, PAUSED, DELETED, ARCHIVED}
     pub conversion_domain: Option<String>, 
     pub created_time: Option<String>, 
-   // pub creative: Option<AdCreative>, 
+    pub creative: Option<AdCreative>,
    // pub creative_asset_groups_spec: Option<AdCreativeAssetGroupsSpec>, 
     pub effective_status: Option<String>, // {ACTIVE, PAUSED, DELETED, PENDING_REVIEW, etc.}
     pub issues_info: Option<Vec<AdgroupIssuesInfo>>, 
@@ -407,135 +643,424 @@ pub struct AdgroupPlacementSpecificReviewFeedback {
     pub unified_commerce_content: Option<HashMap<String, String>>,
     pub whatsapp: Option<HashMap<String, String>>,
 }
-//#[derive(Debug, Deserialize)]
-// pub struct AdCreative {
-//     pub id: Option<String>,
-//     pub account_id: Option<String>,
-//     pub actor_id: Option<String>,
-//     pub ad_disclaimer_spec: Option<AdCreativeAdDisclaimer>,
-//     pub adlabels: Option<Vec<AdLabel>>,
-//     pub applink_treatment: Option<String>,
-//     pub asset_feed_spec: Option<AdAssetFeedSpec>,
-//     pub authorization_category: Option<String>,
-//     pub body: Option<String>,
-//     pub branded_content: Option<AdCreativeBrandedContentAds>,
-//     pub branded_content_sponsor_page_id: Option<String>,
-//     pub bundle_folder_id: Option<String>,
-//     pub call_to_action_type: Option<String>,
-//     pub categorization_criteria: Option<String>,
-//     pub category_media_source: Option<String>,
-//     pub collaborative_ads_lsb_image_bank_id: Option<String>,
-//     pub contextual_multi_ads: Option<AdCreativeContextualMultiAds>,
-//     pub creative_sourcing_spec: Option<AdCreativeSourcingSpec>,
-//     pub degrees_of_freedom_spec: Option<AdCreativeDegreesOfFreedomSpec>,
-//     pub destination_set_id: Option<String>,
-//     pub dynamic_ad_voice: Option<String>,
-//     pub effective_authorization_category: Option<String>,
-//     pub effective_instagram_media_id: Option<String>,
-//     pub effective_object_story_id: Option<String>,
-//     pub enable_direct_install: Option<bool>,
-//     pub enable_launch_instant_app: Option<bool>,
-//     pub facebook_branded_content: Option<AdCreativeFacebookBrandedContent>,
-//     pub image_crops: Option<AdsImageCrops>,
-//     pub image_hash: Option<String>,
-//     pub image_url: Option<String>,
-//     pub instagram_permalink_url: Option<String>,
-//     pub instagram_user_id: Option<String>,
-//     pub interactive_components_spec: Option<AdCreativeInteractiveComponentsSpec>,
-//     pub link_destination_display_url: Option<String>,
-//     pub link_og_id: Option<String>,
-//     pub link_url: Option<String>,
-//     pub messenger_sponsored_message: Option<String>,
-//     pub name: Option<String>,
-//     pub object_id: Option<String>,
-//     pub object_store_url: Option<String>,
-//     pub object_story_id: Option<String>,
-//     pub object_story_spec: Option<AdCreativeObjectStorySpec>,
-//     pub object_type: Option<String>,
-//     pub object_url: Option<String>,
-//     pub page_welcome_message: Option<String>,
-//     pub photo_album_source_object_story_id: Option<String>,
-//     pub place_page_set_id: Option<String>,
-//     pub platform_customizations: Option<AdCreativePlatformCustomization>,
-//     pub playable_asset_id: Option<String>,
-//     pub portrait_customizations: Option<AdCreativePortraitCustomizations>,
-//     pub product_data: Option<Vec<AdCreativeProductData>>,
-//     pub product_set_id: Option<String>,
-//     pub recommender_settings: Option<AdCreativeRecommenderSettings>,
-//     pub referral_id: Option<String>,
-//     pub regional_regulation_disclaimer_spec: Option<AdCreativeRegionalRegulationDisclaimer>,
-//     pub source_instagram_media_id: Option<String>,
-//     pub status: Option<String>,
-//     pub template_url: Option<String>,
-//     pub template_url_spec: Option<AdCreativeTemplateURLSpec>,
-//     pub thumbnail_id: Option<String>,
-//     pub thumbnail_url: Option<String>,
-//     pub title: Option<String>,
-//     pub url_tags: Option<String>,
-//     pub use_page_actor_override: Option<bool>,
-//     pub video_id: Option<String>,
-// }
-// #[derive(Debug, Deserialize)]
-// struct AdCreativeAdDisclaimer{
-// pub text:Option<String>,
-// pub title:Option<String>,
-// pub url:Option<String>,
-// }
-// #[derive(Debug, Deserialize)]
-// pub struct AdAssetFeedSpec {
-//     pub ad_formats: Option<Vec<String>>,
-//     pub additional_data: Option<AdAssetFeedAdditionalData>,
-//     pub app_product_page_id: Option<String>,
-//     pub asset_customization_rules: Option<Vec<AdAssetFeedSpecAssetCustomizationRule>>,
-//     pub audios: Option<Vec<AdAssetAudios>>,
-//     pub autotranslate: Option<Vec<String>>,
-//     pub bodies: Option<Vec<AdAssetFeedSpecBody>>,
-//     pub call_ads_configuration: Option<AdAssetCallAdsConfigurationFeedSpec>,
-//     pub call_to_action_types: Option<Vec<String>>,
-//     pub call_to_actions: Option<Vec<AdAssetFeedSpecCallToAction>>,
-//     pub captions: Option<Vec<AdAssetFeedSpecCaption>>,
-//     pub ctwa_consent_data: Option<Vec<AdAssetCtwaConsentData>>,
-//     pub descriptions: Option<Vec<AdAssetFeedSpecDescription>>,
-//     pub events: Option<Vec<AdAssetFeedSpecEvents>>,
-//     pub groups: Option<Vec<AdAssetFeedSpecGroupRule>>,
-//     pub images: Option<Vec<AdAssetFeedSpecImage>>,
-//     pub link_urls: Option<Vec<AdAssetFeedSpecLinkURL>>,
-//     pub message_extensions: Option<Vec<AdAssetMessageExtensions>>,
-//     pub onsite_destinations: Option<Vec<AdAssetOnsiteDestinations>>,
-//     pub optimization_type: Option<String>,
-//     pub promotional_metadata: Option<AdAssetPromotionalMetadata>,
-//     pub reasons_to_shop: Option<bool>,
-//     pub shops_bundle: Option<bool>,
-//     pub titles: Option<Vec<AdAssetFeedSpecTitle>>,
-//     pub upcoming_events: Option<Vec<AdAssetUpcomingEvents>>,
-//     pub videos: Option<Vec<AdAssetFeedSpecVideo>>,
-// }
-
-// #[derive(Debug, Deserialize)]
-// pub struct AdAssetFeedAdditionalData {
-//     pub automated_product_tags: Option<bool>,
-//     pub brand_page_id: Option<String>,
-//     pub is_click_to_message: Option<bool>,
-//     pub multi_share_end_card: Option<bool>,
-//     pub page_welcome_message: Option<String>,
-//     pub partner_app_welcome_message_flow_id: Option<String>,
-// }
-// #[derive(Debug, Deserialize)]
-// pub struct AdAssetFeedSpecAssetCustomizationRule {
-//     pub body_label: Option<AdAssetFeedSpecAssetLabel>,
-//     pub call_to_action_label: Option<AdAssetFeedSpecAssetLabel>,
-//     pub call_to_action_type_label: Option<AdAssetFeedSpecAssetLabel>, // PLM CEA MAI PROASTA DOCUMENTATIE
-//     pub caption_label: Option<AdAssetFeedSpecAssetLabel>,
-//     pub carousel_label: Option<AdAssetFeedSpecAssetLabel>,
-//     pub customization_spec: Option<AdAssetCustomizationRuleCustomizationSpec>,
-//     pub description_label: Option<AdAssetFeedSpecAssetLabel>,
-//     pub image_label: Option<AdAssetFeedSpecAssetLabel>,
-//     pub is_default: Option<bool>,
-//     pub link_url_label: Option<AdAssetFeedSpecAssetLabel>,
-//     pub priority: Option<i32>,
-//     pub title_label: Option<AdAssetFeedSpecAssetLabel>,
-//     pub video_label: Option<AdAssetFeedSpecAssetLabel>,
-// }
+pub type AdCreativesResponse = ListResponse<AdCreative>;
+
+/// An ad creative. See <https://developers.facebook.com/docs/marketing-api/reference/ad-creative>.
+///
+/// Specs whose shape Graph does not document precisely are kept as raw JSON.
+#[derive(Debug, Deserialize)]
+pub struct AdCreative {
+    pub id: Option<String>,
+    pub account_id: Option<String>,
+    pub actor_id: Option<String>,
+    pub ad_disclaimer_spec: Option<AdCreativeAdDisclaimer>,
+    pub adlabels: Option<Vec<AdLabel>>,
+    pub applink_treatment: Option<String>,
+    pub asset_feed_spec: Option<AdAssetFeedSpec>,
+    pub authorization_category: Option<String>,
+    pub body: Option<String>,
+    pub branded_content: Option<serde_json::Value>,
+    pub branded_content_sponsor_page_id: Option<String>,
+    pub bundle_folder_id: Option<String>,
+    pub call_to_action_type: Option<String>,
+    pub categorization_criteria: Option<String>,
+    pub category_media_source: Option<String>,
+    pub collaborative_ads_lsb_image_bank_id: Option<String>,
+    pub contextual_multi_ads: Option<serde_json::Value>,
+    pub creative_sourcing_spec: Option<serde_json::Value>,
+    pub degrees_of_freedom_spec: Option<serde_json::Value>,
+    pub destination_set_id: Option<String>,
+    pub dynamic_ad_voice: Option<String>,
+    pub effective_authorization_category: Option<String>,
+    pub effective_instagram_media_id: Option<String>,
+    pub effective_object_story_id: Option<String>,
+    pub enable_direct_install: Option<bool>,
+    pub enable_launch_instant_app: Option<bool>,
+    pub facebook_branded_content: Option<serde_json::Value>,
+    pub image_crops: Option<AdsImageCrops>,
+    pub image_hash: Option<String>,
+    pub image_url: Option<String>,
+    pub instagram_permalink_url: Option<String>,
+    pub instagram_user_id: Option<String>,
+    pub interactive_components_spec: Option<serde_json::Value>,
+    pub link_destination_display_url: Option<String>,
+    pub link_og_id: Option<String>,
+    pub link_url: Option<String>,
+    pub messenger_sponsored_message: Option<String>,
+    pub name: Option<String>,
+    pub object_id: Option<String>,
+    pub object_store_url: Option<String>,
+    pub object_story_id: Option<String>,
+    pub object_story_spec: Option<AdCreativeObjectStorySpec>,
+    pub object_type: Option<String>,
+    pub object_url: Option<String>,
+    pub page_welcome_message: Option<String>,
+    pub photo_album_source_object_story_id: Option<String>,
+    pub place_page_set_id: Option<String>,
+    pub platform_customizations: Option<serde_json::Value>,
+    pub playable_asset_id: Option<String>,
+    pub portrait_customizations: Option<serde_json::Value>,
+    pub product_data: Option<Vec<serde_json::Value>>,
+    pub product_set_id: Option<String>,
+    pub recommender_settings: Option<serde_json::Value>,
+    pub referral_id: Option<String>,
+    pub regional_regulation_disclaimer_spec: Option<serde_json::Value>,
+    pub source_instagram_media_id: Option<String>,
+    pub status: Option<String>,
+    pub template_url: Option<String>,
+    pub template_url_spec: Option<serde_json::Value>,
+    pub thumbnail_id: Option<String>,
+    pub thumbnail_url: Option<String>,
+    pub title: Option<String>,
+    pub url_tags: Option<String>,
+    pub use_page_actor_override: Option<bool>,
+    pub video_id: Option<String>,
+}
+
+/// Crops of an image keyed by aspect ratio (`"100x100"`, `"191x100"`, ...), each as
+/// `[[left, top], [right, bottom]]` in pixels.
+pub type AdsImageCrops = HashMap<String, Vec<Vec<i64>>>;
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct AdCreativeAdDisclaimer {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub text: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+}
+
+/// The page post an ad creative is built from. Set exactly one of `link_data`,
+/// `photo_data`, `video_data` and `template_data`; dynamic creatives set only the page
+/// and identity, and carry their assets in an [`AdAssetFeedSpec`].
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct AdCreativeObjectStorySpec {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub page_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub instagram_user_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub link_data: Option<AdCreativeLinkData>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub photo_data: Option<AdCreativePhotoData>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub video_data: Option<AdCreativeVideoData>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub template_data: Option<AdCreativeLinkData>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub text_data: Option<AdCreativeTextData>,
+}
+
+/// A link ad, or a carousel when `child_attachments` is set.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct AdCreativeLinkData {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub link: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub picture: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub image_hash: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub image_crops: Option<AdsImageCrops>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub call_to_action: Option<AdCreativeLinkDataCallToAction>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub child_attachments: Option<Vec<AdCreativeLinkDataChildAttachment>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub multi_share_optimized: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub multi_share_end_card: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub attachment_style: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub format_option: Option<String>,
+}
+
+/// One card of a carousel.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct AdCreativeLinkDataChildAttachment {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub link: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub picture: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub image_hash: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub image_crops: Option<AdsImageCrops>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub video_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub call_to_action: Option<AdCreativeLinkDataCallToAction>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AdCreativeLinkDataCallToAction {
+    #[serde(rename = "type")]
+    pub call_to_action_type: String, // LEARN_MORE, SHOP_NOW, SIGN_UP, ...
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub value: Option<AdCreativeLinkDataCallToActionValue>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct AdCreativeLinkDataCallToActionValue {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub link: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub link_caption: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub app_link: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub application: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub lead_gen_form_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub page: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct AdCreativePhotoData {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub image_hash: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub page_welcome_message: Option<String>,
+}
+
+/// A video ad. Graph requires a thumbnail through `image_hash` or `image_url`.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct AdCreativeVideoData {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub video_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub link_description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub image_hash: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub image_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub call_to_action: Option<AdCreativeLinkDataCallToAction>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct AdCreativeTextData {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+}
+
+/// Assets of a dynamic creative, which Graph combines per placement and audience.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct AdAssetFeedSpec {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ad_formats: Option<Vec<String>>, // SINGLE_IMAGE, CAROUSEL, SINGLE_VIDEO, AUTOMATIC_FORMAT
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub additional_data: Option<AdAssetFeedAdditionalData>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub app_product_page_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub asset_customization_rules: Option<Vec<AdAssetFeedSpecAssetCustomizationRule>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub audios: Option<Vec<serde_json::Value>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub autotranslate: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bodies: Option<Vec<AdAssetFeedSpecBody>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub call_ads_configuration: Option<serde_json::Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub call_to_action_types: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub call_to_actions: Option<Vec<serde_json::Value>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub captions: Option<Vec<AdAssetFeedSpecCaption>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ctwa_consent_data: Option<Vec<serde_json::Value>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub descriptions: Option<Vec<AdAssetFeedSpecDescription>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub events: Option<Vec<serde_json::Value>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub groups: Option<Vec<serde_json::Value>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub images: Option<Vec<AdAssetFeedSpecImage>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub link_urls: Option<Vec<AdAssetFeedSpecLinkURL>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_extensions: Option<Vec<serde_json::Value>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub onsite_destinations: Option<Vec<serde_json::Value>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub optimization_type: Option<String>, // REGULAR, PLACEMENT, LANGUAGE, ...
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub promotional_metadata: Option<serde_json::Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reasons_to_shop: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub shops_bundle: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub titles: Option<Vec<AdAssetFeedSpecTitle>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub upcoming_events: Option<Vec<serde_json::Value>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub videos: Option<Vec<AdAssetFeedSpecVideo>>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct AdAssetFeedAdditionalData {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub automated_product_tags: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub brand_page_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_click_to_message: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub multi_share_end_card: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub page_welcome_message: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub partner_app_welcome_message_flow_id: Option<String>,
+}
+
+/// Pairs labelled assets with the placements or audiences they are shown to.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct AdAssetFeedSpecAssetCustomizationRule {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub body_label: Option<AdAssetFeedSpecAssetLabel>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub call_to_action_label: Option<AdAssetFeedSpecAssetLabel>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub call_to_action_type_label: Option<AdAssetFeedSpecAssetLabel>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption_label: Option<AdAssetFeedSpecAssetLabel>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub carousel_label: Option<AdAssetFeedSpecAssetLabel>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub customization_spec: Option<AdAssetCustomizationRuleCustomizationSpec>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description_label: Option<AdAssetFeedSpecAssetLabel>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub image_label: Option<AdAssetFeedSpecAssetLabel>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_default: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub link_url_label: Option<AdAssetFeedSpecAssetLabel>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub priority: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title_label: Option<AdAssetFeedSpecAssetLabel>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub video_label: Option<AdAssetFeedSpecAssetLabel>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct AdAssetCustomizationRuleCustomizationSpec {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub age_min: Option<u8>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub age_max: Option<u8>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub geo_locations: Option<GeoLocations>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub locales: Option<Vec<i32>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub publisher_platforms: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub facebook_positions: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub instagram_positions: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub audience_network_positions: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub messenger_positions: Option<Vec<String>>,
+}
+
+/// Label tying an asset to customization rules. Name it when creating; Graph assigns the ID.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct AdAssetFeedSpecAssetLabel {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+}
+
+/// A text asset of an [`AdAssetFeedSpec`]: a body, title, description or caption.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct AdAssetFeedSpecText {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub text: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub adlabels: Option<Vec<AdAssetFeedSpecAssetLabel>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url_tags: Option<String>,
+}
+
+pub type AdAssetFeedSpecBody = AdAssetFeedSpecText;
+pub type AdAssetFeedSpecTitle = AdAssetFeedSpecText;
+pub type AdAssetFeedSpecDescription = AdAssetFeedSpecText;
+pub type AdAssetFeedSpecCaption = AdAssetFeedSpecText;
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct AdAssetFeedSpecImage {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hash: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub image_crops: Option<AdsImageCrops>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub adlabels: Option<Vec<AdAssetFeedSpecAssetLabel>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url_tags: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct AdAssetFeedSpecVideo {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub video_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub thumbnail_hash: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub thumbnail_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub adlabels: Option<Vec<AdAssetFeedSpecAssetLabel>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url_tags: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct AdAssetFeedSpecLinkURL {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub website_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub display_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub deeplink_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub carousel_see_more_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url_tags: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub adlabels: Option<Vec<AdAssetFeedSpecAssetLabel>>,
+}
 
 #[derive(Debug, Deserialize)]
 pub struct AdgroupIssuesInfo {
@@ -575,14 +1100,58 @@ pub struct ConversionActionQuery {
     pub subtype: Option<Vec<String>>,
 }
 
+pub type CustomAudiencesResponse = ListResponse<CustomAudience>;
+
+/// A custom audience. See <https://developers.facebook.com/docs/marketing-api/reference/custom-audience>.
+#[derive(Debug, Deserialize)]
+pub struct CustomAudience {
+    pub id: Option<String>,
+    pub account_id: Option<String>,
+    pub name: Option<String>,
+    pub description: Option<String>,
+    pub subtype: Option<String>, // CUSTOM, WEBSITE, LOOKALIKE, ...
+    pub customer_file_source: Option<String>,
+    pub data_source: Option<CustomAudienceDataSource>,
+    pub approximate_count_lower_bound: Option<i64>,
+    pub approximate_count_upper_bound: Option<i64>,
+    pub operation_status: Option<CustomAudienceStatus>,
+    pub delivery_status: Option<CustomAudienceStatus>,
+    pub lookalike_spec: Option<serde_json::Value>,
+    pub lookalike_audience_ids: Option<Vec<String>>,
+    pub retention_days: Option<u32>,
+    pub rule: Option<String>,
+    pub is_value_based: Option<bool>,
+    pub sharing_status: Option<serde_json::Value>,
+    pub permission_for_actions: Option<serde_json::Value>,
+    pub time_created: Option<i64>,
+    pub time_updated: Option<i64>,
+    pub time_content_updated: Option<i64>,
+}
+
+/// An `operation_status` or `delivery_status` of a custom audience.
+#[derive(Debug, Clone, Deserialize)]
+pub struct CustomAudienceStatus {
+    pub code: i64,
+    pub description: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct CustomAudienceDataSource {
+    #[serde(rename = "type")]
+    pub source_type: Option<String>,
+    pub sub_type: Option<String>,
+    pub creation_params: Option<String>,
+}
+
 impl CampaignsResponse {
     pub fn display(&self) {
         println!("Displaying Campaigns:");
 
-        println!("Paging Information:");
-        println!("Before Cursor: {}", self.paging.cursors.before);
-        println!("After Cursor: {}", self.paging.cursors.after);
-        println!("----------------------------------------");
+        if let Some(paging) = &self.paging {
+            println!("Paging Information:");
+            paging.display();
+            println!("----------------------------------------");
+        }
 
         for campaign in &self.data {
             if let Some(id) = &campaign.id {
@@ -887,7 +1456,7 @@ impl CampaignsResponse {
                 println!("Smart Promotion Type: {}", smart_promotion_type);
             }
 
-            if let Some(source_campaign) = &campaign.source_campaign {
+            if let Some(_source_campaign) = &campaign.source_campaign {
                 println!("Source Campaign: ", /*source_campaign*/);
             }
 
@@ -1239,7 +1808,7 @@ impl Campaign {
             println!("Smart Promotion Type: {}", smart_promotion_type);
         }
 
-        if let Some(source_campaign) = &self.source_campaign {
+        if let Some(_source_campaign) = &self.source_campaign {
             println!("Source Campaign: ", /*source_campaign*/);
         }
 
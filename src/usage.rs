@@ -0,0 +1,268 @@
+//! Rate-limit usage reported by Graph in response headers.
+//!
+//! Graph reports throttling state on every response through `x-app-usage`,
+//! `x-ad-account-usage` and `x-business-use-case-usage`. The client parses them
+//! into a [`UsageSnapshot`] and keeps the latest values in a [`UsageTracker`].
+//!
+//! See <https://developers.facebook.com/docs/graph-api/overview/rate-limiting>.
+
+use reqwest::header::HeaderMap;
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+/// How long to pause when usage is over the threshold but Graph gave no estimate.
+const DEFAULT_USAGE_COOLDOWN: Duration = Duration::from_secs(60);
+
+/// `x-app-usage`: application-level usage, each value a percentage of the limit.
+#[derive(Deserialize, Debug, Clone, Default)]
+pub struct AppUsage {
+    #[serde(default)]
+    pub call_count: f64,
+    #[serde(default)]
+    pub total_time: f64,
+    #[serde(default)]
+    pub total_cputime: f64,
+}
+
+impl AppUsage {
+    pub fn max_pct(&self) -> f64 {
+        self.call_count.max(self.total_time).max(self.total_cputime)
+    }
+}
+
+/// `x-ad-account-usage`: usage of the ad account the request was made against.
+#[derive(Deserialize, Debug, Clone, Default)]
+pub struct AdAccountUsage {
+    #[serde(default)]
+    pub acc_id_util_pct: f64,
+    /// Seconds until the account's usage is reset.
+    #[serde(default)]
+    pub reset_time_duration: u64,
+    pub ads_api_access_tier: Option<String>,
+}
+
+/// One entry of `x-business-use-case-usage` for a business.
+#[derive(Deserialize, Debug, Clone, Default)]
+pub struct BusinessUseCaseUsage {
+    #[serde(rename = "type")]
+    pub use_case: Option<String>,
+    #[serde(default)]
+    pub call_count: f64,
+    #[serde(default)]
+    pub total_time: f64,
+    #[serde(default)]
+    pub total_cputime: f64,
+    /// Minutes until calls are accepted again; `0` when not throttled.
+    #[serde(default)]
+    pub estimated_time_to_regain_access: u64,
+    pub ads_api_access_tier: Option<String>,
+}
+
+impl BusinessUseCaseUsage {
+    pub fn max_pct(&self) -> f64 {
+        self.call_count.max(self.total_time).max(self.total_cputime)
+    }
+}
+
+/// Usage headers of a single response.
+#[derive(Debug, Clone)]
+pub struct UsageSnapshot {
+    pub app: Option<AppUsage>,
+    pub ad_account: Option<AdAccountUsage>,
+    /// Keyed by business ID.
+    pub business_use_case: HashMap<String, Vec<BusinessUseCaseUsage>>,
+    pub observed_at: Instant,
+}
+
+impl UsageSnapshot {
+    /// Parses the usage headers of a response, returning `None` if none were sent.
+    ///
+    /// Headers that are present but malformed are ignored.
+    pub fn from_headers(headers: &HeaderMap) -> Option<Self> {
+        fn parse<T: for<'de> Deserialize<'de>>(headers: &HeaderMap, name: &str) -> Option<T> {
+            let value = headers.get(name)?.to_str().ok()?;
+            serde_json::from_str(value).ok()
+        }
+
+        let snapshot = UsageSnapshot {
+            app: parse(headers, "x-app-usage"),
+            ad_account: parse(headers, "x-ad-account-usage"),
+            business_use_case: parse(headers, "x-business-use-case-usage").unwrap_or_default(),
+            observed_at: Instant::now(),
+        };
+        if snapshot.app.is_none() && snapshot.ad_account.is_none() && snapshot.business_use_case.is_empty() {
+            None
+        } else {
+            Some(snapshot)
+        }
+    }
+
+    /// The highest utilization percentage reported by any header.
+    pub fn max_pct(&self) -> f64 {
+        let app = self.app.as_ref().map_or(0.0, AppUsage::max_pct);
+        let account = self.ad_account.as_ref().map_or(0.0, |usage| usage.acc_id_util_pct);
+        let business = self
+            .business_use_case
+            .values()
+            .flatten()
+            .map(BusinessUseCaseUsage::max_pct)
+            .fold(0.0, f64::max);
+        app.max(account).max(business)
+    }
+
+    /// How long Graph asked callers to wait, measured from when the snapshot was taken.
+    ///
+    /// Only a business use case's `estimated_time_to_regain_access` means calls are
+    /// being refused; the ad account's `reset_time_duration` is sent even when usage is low.
+    pub fn regain_access_after(&self) -> Option<Duration> {
+        self.business_use_case
+            .values()
+            .flatten()
+            .map(|usage| usage.estimated_time_to_regain_access)
+            .max()
+            .filter(|minutes| *minutes > 0)
+            .map(|minutes| Duration::from_secs(minutes * 60))
+    }
+
+    /// Time left to wait before calling again if usage is at or above `threshold_pct`.
+    fn pause_for(&self, threshold_pct: f64) -> Option<Duration> {
+        let wait = match self.regain_access_after() {
+            Some(wait) => wait,
+            None if self.max_pct() >= threshold_pct => self
+                .ad_account
+                .as_ref()
+                .map(|usage| usage.reset_time_duration)
+                .filter(|seconds| *seconds > 0)
+                .map_or(DEFAULT_USAGE_COOLDOWN, Duration::from_secs),
+            None => return None,
+        };
+        wait.checked_sub(self.observed_at.elapsed())
+    }
+}
+
+/// Each entry keeps only the part of a snapshot it is indexed by, so that a
+/// busy business does not pause calls against an idle ad account and vice versa.
+#[derive(Debug, Default)]
+struct TrackerState {
+    app: Option<UsageSnapshot>,
+    ad_accounts: HashMap<String, UsageSnapshot>,
+    businesses: HashMap<String, UsageSnapshot>,
+    /// Businesses whose usage was reported on responses for each ad account.
+    account_businesses: HashMap<String, Vec<String>>,
+    last: Option<UsageSnapshot>,
+}
+
+/// The latest usage reported by Graph, per ad account and per business.
+///
+/// Shared by all clones of a `FacebookClient`.
+#[derive(Debug, Default)]
+pub struct UsageTracker {
+    state: Mutex<TrackerState>,
+}
+
+impl UsageTracker {
+    /// Records a snapshot. `ad_account_id` is the account the request targeted, if known.
+    pub fn record(&self, ad_account_id: Option<&str>, snapshot: UsageSnapshot) {
+        let empty = UsageSnapshot {
+            app: None,
+            ad_account: None,
+            business_use_case: HashMap::new(),
+            observed_at: snapshot.observed_at,
+        };
+        let mut state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+        if snapshot.app.is_some() {
+            state.app = Some(UsageSnapshot {
+                app: snapshot.app.clone(),
+                ..empty.clone()
+            });
+        }
+        for (business_id, usage) in &snapshot.business_use_case {
+            state.businesses.insert(
+                business_id.clone(),
+                UsageSnapshot {
+                    business_use_case: HashMap::from([(business_id.clone(), usage.clone())]),
+                    ..empty.clone()
+                },
+            );
+        }
+        if let Some(ad_account_id) = ad_account_id {
+            let businesses = state.account_businesses.entry(ad_account_id.to_string()).or_default();
+            for business_id in snapshot.business_use_case.keys() {
+                if !businesses.contains(business_id) {
+                    businesses.push(business_id.clone());
+                }
+            }
+        }
+        if let (Some(ad_account_id), Some(_)) = (ad_account_id, &snapshot.ad_account) {
+            state.ad_accounts.insert(
+                ad_account_id.to_string(),
+                UsageSnapshot {
+                    ad_account: snapshot.ad_account.clone(),
+                    ..empty
+                },
+            );
+        }
+        state.last = Some(snapshot);
+    }
+
+    /// Latest application-level usage.
+    pub fn app(&self) -> Option<AppUsage> {
+        let state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+        state.app.as_ref().and_then(|snapshot| snapshot.app.clone())
+    }
+
+    /// Latest usage of an ad account, with or without the `act_` prefix.
+    pub fn ad_account(&self, ad_account_id: &str) -> Option<AdAccountUsage> {
+        let state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+        state
+            .ad_accounts
+            .get(ad_account_id.trim_start_matches("act_"))
+            .and_then(|snapshot| snapshot.ad_account.clone())
+    }
+
+    /// Latest business use case usage of a business.
+    pub fn business(&self, business_id: &str) -> Option<Vec<BusinessUseCaseUsage>> {
+        let state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+        state
+            .businesses
+            .get(business_id)
+            .and_then(|snapshot| snapshot.business_use_case.get(business_id).cloned())
+    }
+
+    /// The most recent snapshot of any request.
+    pub fn last(&self) -> Option<UsageSnapshot> {
+        let state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+        state.last.clone()
+    }
+
+    /// How long to wait before a request against `ad_account_id` so that usage
+    /// at or above `threshold_pct` has time to recover.
+    ///
+    /// Considers the app, the ad account and the businesses reported on earlier
+    /// responses for that account. Requests without an ad account only wait on the app.
+    pub fn pause_before(&self, ad_account_id: Option<&str>, threshold_pct: f64) -> Option<Duration> {
+        let state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+        let account = ad_account_id.and_then(|id| state.ad_accounts.get(id));
+        let businesses = ad_account_id
+            .and_then(|id| state.account_businesses.get(id))
+            .into_iter()
+            .flatten()
+            .filter_map(|business_id| state.businesses.get(business_id));
+        state
+            .app
+            .iter()
+            .chain(account)
+            .chain(businesses)
+            .filter_map(|snapshot| snapshot.pause_for(threshold_pct))
+            .max()
+    }
+}
+
+/// Extracts the ad account ID from a Graph path such as `act_123/campaigns`.
+pub(crate) fn ad_account_id_from_path(path: &str) -> Option<&str> {
+    path.split('/')
+        .find_map(|segment| segment.strip_prefix("act_"))
+        .map(|id| id.split('?').next().unwrap_or(id))
+}